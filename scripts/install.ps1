@@ -82,10 +82,10 @@ try {
     $webClient.DownloadFile($downloadUrl, $tempFile)
 }
 catch {
-    # Try direct download from dist directory as fallback
+    # Try the GitHub release asset (same name `goa self update` downloads) as fallback
     try {
         Write-Host "Primary download failed, trying fallback method..." -ForegroundColor Yellow
-        $fallbackUrl = "https://raw.githubusercontent.com/goonairplanes/goa-cli/main/dist/goa-windows.exe"
+        $fallbackUrl = "https://github.com/kleeedolinux/goa-cli/releases/latest/download/goa-windows-x86_64.exe"
         
         $webClient = New-Object System.Net.WebClient
         $webClient.Headers.Add("User-Agent", "GOA-CLI-Installer")
@@ -158,8 +158,8 @@ if [[ "$OS" == "Linux" ]]; then
 
     # Primary download URL with HTTPS
     DOWNLOAD_URL="https://re.juliaklee.wtf/linux"
-    # Fallback URL as direct GitHub download
-    FALLBACK_URL="https://raw.githubusercontent.com/goonairplanes/goa-cli/main/dist/goa-linux"
+    # Fallback URL as the GitHub release asset (same name `goa self update` downloads)
+    FALLBACK_URL="https://github.com/kleeedolinux/goa-cli/releases/latest/download/goa-linux-$(uname -m)"
 
     # Download the binary
     print_step "Downloading GOA CLI from mirror service..."
@@ -0,0 +1,622 @@
+use std::collections::BTreeSet;
+
+const BREAKPOINTS: [(&str, &str); 5] = [
+    ("sm", "640px"),
+    ("md", "768px"),
+    ("lg", "1024px"),
+    ("xl", "1280px"),
+    ("2xl", "1536px"),
+];
+
+const PSEUDO_VARIANTS: [(&str, &str); 7] = [
+    ("hover", ":hover"),
+    ("focus", ":focus"),
+    ("active", ":active"),
+    ("disabled", ":disabled"),
+    ("visited", ":visited"),
+    ("first", ":first-child"),
+    ("last", ":last-child"),
+];
+
+const PALETTE: [(&str, [&str; 10]); 8] = [
+    ("slate", ["#f8fafc", "#f1f5f9", "#e2e8f0", "#cbd5e1", "#94a3b8", "#64748b", "#475569", "#334155", "#1e293b", "#0f172a"]),
+    ("gray", ["#f9fafb", "#f3f4f6", "#e5e7eb", "#d1d5db", "#9ca3af", "#6b7280", "#4b5563", "#374151", "#1f2937", "#111827"]),
+    ("red", ["#fef2f2", "#fee2e2", "#fecaca", "#fca5a5", "#f87171", "#ef4444", "#dc2626", "#b91c1c", "#991b1b", "#7f1d1d"]),
+    ("yellow", ["#fefce8", "#fef9c3", "#fef08a", "#fde047", "#facc15", "#eab308", "#ca8a04", "#a16207", "#854d0e", "#713f12"]),
+    ("green", ["#f0fdf4", "#dcfce7", "#bbf7d0", "#86efac", "#4ade80", "#22c55e", "#16a34a", "#15803d", "#166534", "#14532d"]),
+    ("blue", ["#eff6ff", "#dbeafe", "#bfdbfe", "#93c5fd", "#60a5fa", "#3b82f6", "#2563eb", "#1d4ed8", "#1e40af", "#1e3a8a"]),
+    ("indigo", ["#eef2ff", "#e0e7ff", "#c7d2fe", "#a5b4fc", "#818cf8", "#6366f1", "#4f46e5", "#4338ca", "#3730a3", "#312e81"]),
+    ("purple", ["#faf5ff", "#f3e8ff", "#e9d5ff", "#d8b4fe", "#c084fc", "#a855f7", "#9333ea", "#7e22ce", "#6b21a8", "#581c87"]),
+];
+
+const SHADES: [&str; 10] = ["50", "100", "200", "300", "400", "500", "600", "700", "800", "900"];
+
+const PREFLIGHT: &str = "*,::before,::after{box-sizing:border-box;border-width:0;border-style:solid;border-color:#e5e7eb}\
+html{line-height:1.5;-webkit-text-size-adjust:100%;tab-size:4;font-family:ui-sans-serif,system-ui,-apple-system,\"Segoe UI\",Roboto,\"Helvetica Neue\",Arial,sans-serif}\
+body{margin:0;line-height:inherit}\
+h1,h2,h3,h4,h5,h6{font-size:inherit;font-weight:inherit}\
+blockquote,dl,dd,h1,h2,h3,h4,h5,h6,hr,figure,p,pre{margin:0}\
+a{color:inherit;text-decoration:inherit}\
+ol,ul,menu{list-style:none;margin:0;padding:0}\
+img,svg,video,canvas,audio,iframe,embed,object{display:block;vertical-align:middle}\
+img,video{max-width:100%;height:auto}\
+button,input,optgroup,select,textarea{font-family:inherit;font-size:100%;font-weight:inherit;line-height:inherit;color:inherit;margin:0;padding:0}\
+button,[role=\"button\"]{cursor:pointer}\
+code,kbd,samp,pre{font-family:ui-monospace,SFMono-Regular,Menlo,Monaco,Consolas,monospace;font-size:1em}\
+table{text-indent:0;border-color:inherit;border-collapse:collapse}\
+[hidden]{display:none}";
+
+struct Rule {
+    declarations: String,
+    selector_suffix: &'static str,
+}
+
+pub struct GeneratedCss {
+    pub css: String,
+    pub generated: usize,
+    pub unknown: Vec<String>,
+}
+
+pub fn generate(classes: &BTreeSet<String>) -> GeneratedCss {
+    let mut base = String::new();
+    let mut responsive: Vec<String> = vec![String::new(); BREAKPOINTS.len()];
+    let mut generated = 0;
+    let mut unknown = Vec::new();
+
+    for class in classes {
+        let mut parts: Vec<&str> = class.split(':').collect();
+        let utility = parts.pop().unwrap_or_default();
+
+        let mut breakpoint = None;
+        let mut pseudo = String::new();
+        let mut valid = true;
+
+        for variant in parts {
+            if let Some(index) = BREAKPOINTS.iter().position(|(name, _)| *name == variant) {
+                breakpoint = Some(index);
+            } else if let Some((_, selector)) = PSEUDO_VARIANTS.iter().find(|(name, _)| *name == variant) {
+                pseudo.push_str(selector);
+            } else {
+                valid = false;
+            }
+        }
+
+        let rule = if valid { utility_rule(utility) } else { None };
+        let rule = match rule {
+            Some(rule) => rule,
+            None => {
+                unknown.push(class.clone());
+                continue;
+            }
+        };
+
+        let css_rule = format!(".{}{}{}{{{}}}", escape_class(class), pseudo, rule.selector_suffix, rule.declarations);
+        match breakpoint {
+            Some(index) => responsive[index].push_str(&css_rule),
+            None => base.push_str(&css_rule),
+        }
+        generated += 1;
+    }
+
+    let mut css = String::from(PREFLIGHT);
+    css.push_str(&base);
+    for (index, rules) in responsive.iter().enumerate() {
+        if !rules.is_empty() {
+            css.push_str(&format!("@media (min-width:{}){{{}}}", BREAKPOINTS[index].1, rules));
+        }
+    }
+
+    GeneratedCss { css, generated, unknown }
+}
+
+fn utility_rule(utility: &str) -> Option<Rule> {
+    if let Some(rest) = utility.strip_prefix("space-x-") {
+        let value = spacing(rest)?;
+        return Some(Rule {
+            declarations: format!("margin-left:{}", value),
+            selector_suffix: ">:not([hidden])~:not([hidden])",
+        });
+    }
+
+    if let Some(rest) = utility.strip_prefix("space-y-") {
+        let value = spacing(rest)?;
+        return Some(Rule {
+            declarations: format!("margin-top:{}", value),
+            selector_suffix: ">:not([hidden])~:not([hidden])",
+        });
+    }
+
+    declarations(utility).map(|declarations| Rule {
+        declarations,
+        selector_suffix: "",
+    })
+}
+
+fn declarations(utility: &str) -> Option<String> {
+    if let Some(declaration) = static_utility(utility) {
+        return Some(declaration.to_string());
+    }
+
+    let spacing_properties: [(&str, &[&str]); 17] = [
+        ("p", &["padding"]),
+        ("px", &["padding-left", "padding-right"]),
+        ("py", &["padding-top", "padding-bottom"]),
+        ("pt", &["padding-top"]),
+        ("pr", &["padding-right"]),
+        ("pb", &["padding-bottom"]),
+        ("pl", &["padding-left"]),
+        ("m", &["margin"]),
+        ("mx", &["margin-left", "margin-right"]),
+        ("my", &["margin-top", "margin-bottom"]),
+        ("mt", &["margin-top"]),
+        ("mr", &["margin-right"]),
+        ("mb", &["margin-bottom"]),
+        ("ml", &["margin-left"]),
+        ("gap", &["gap"]),
+        ("gap-x", &["column-gap"]),
+        ("gap-y", &["row-gap"]),
+    ];
+
+    let (negative, unsigned) = match utility.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, utility),
+    };
+
+    for (prefix, properties) in spacing_properties.iter().rev() {
+        if let Some(rest) = unsigned.strip_prefix(prefix).and_then(|r| r.strip_prefix('-')) {
+            let value = if rest == "auto" && prefix.starts_with('m') && !negative {
+                "auto".to_string()
+            } else {
+                let value = spacing(rest)?;
+                if negative {
+                    if !prefix.starts_with('m') {
+                        return None;
+                    }
+                    format!("-{}", value)
+                } else {
+                    value
+                }
+            };
+            return Some(properties.iter().map(|p| format!("{}:{}", p, value)).collect::<Vec<_>>().join(";"));
+        }
+    }
+
+    if negative {
+        return None;
+    }
+
+    let (prefix, rest) = utility.split_once('-')?;
+    match prefix {
+        "w" => sizing(rest, "vw").map(|v| format!("width:{}", v)),
+        "h" => sizing(rest, "vh").map(|v| format!("height:{}", v)),
+        "min" => {
+            let (axis, value) = rest.split_once('-')?;
+            match (axis, value) {
+                ("w", "0") => Some("min-width:0px".to_string()),
+                ("w", "full") => Some("min-width:100%".to_string()),
+                ("h", "0") => Some("min-height:0px".to_string()),
+                ("h", "full") => Some("min-height:100%".to_string()),
+                ("h", "screen") => Some("min-height:100vh".to_string()),
+                _ => None,
+            }
+        }
+        "max" => {
+            let value = rest.strip_prefix("w-")?;
+            max_width(value).map(|v| format!("max-width:{}", v))
+        }
+        "text" => text_utility(rest),
+        "bg" => color(rest).map(|c| format!("background-color:{}", c)),
+        "border" => border_utility(rest),
+        "rounded" => radius(rest).map(|v| format!("border-radius:{}", v)),
+        "shadow" => shadow(rest).map(|v| format!("box-shadow:{}", v)),
+        "font" => font_utility(rest),
+        "leading" => leading(rest).map(|v| format!("line-height:{}", v)),
+        "tracking" => tracking(rest).map(|v| format!("letter-spacing:{}", v)),
+        "grid" => {
+            let count = rest.strip_prefix("cols-")?;
+            let count: u8 = count.parse().ok().filter(|n| (1..=12).contains(n))?;
+            Some(format!("grid-template-columns:repeat({},minmax(0,1fr))", count))
+        }
+        "col" => {
+            let span = rest.strip_prefix("span-")?;
+            if span == "full" {
+                return Some("grid-column:1/-1".to_string());
+            }
+            let span: u8 = span.parse().ok().filter(|n| (1..=12).contains(n))?;
+            Some(format!("grid-column:span {} / span {}", span, span))
+        }
+        "items" => match rest {
+            "start" => Some("align-items:flex-start".to_string()),
+            "end" => Some("align-items:flex-end".to_string()),
+            "center" => Some("align-items:center".to_string()),
+            "baseline" => Some("align-items:baseline".to_string()),
+            "stretch" => Some("align-items:stretch".to_string()),
+            _ => None,
+        },
+        "justify" => match rest {
+            "start" => Some("justify-content:flex-start".to_string()),
+            "end" => Some("justify-content:flex-end".to_string()),
+            "center" => Some("justify-content:center".to_string()),
+            "between" => Some("justify-content:space-between".to_string()),
+            "around" => Some("justify-content:space-around".to_string()),
+            "evenly" => Some("justify-content:space-evenly".to_string()),
+            _ => None,
+        },
+        "z" => match rest {
+            "0" | "10" | "20" | "30" | "40" | "50" => Some(format!("z-index:{}", rest)),
+            "auto" => Some("z-index:auto".to_string()),
+            _ => None,
+        },
+        "opacity" => {
+            let value: u8 = rest.parse().ok().filter(|n| *n <= 100 && n % 5 == 0)?;
+            Some(format!("opacity:{}", value as f32 / 100.0))
+        }
+        "top" | "right" | "bottom" | "left" => {
+            let value = if rest == "auto" { "auto".to_string() } else { spacing(rest)? };
+            Some(format!("{}:{}", prefix, value))
+        }
+        "inset" => {
+            let value = spacing(rest)?;
+            Some(format!("inset:{}", value))
+        }
+        _ => None,
+    }
+}
+
+fn static_utility(utility: &str) -> Option<&'static str> {
+    let declaration = match utility {
+        "block" => "display:block",
+        "inline-block" => "display:inline-block",
+        "inline" => "display:inline",
+        "flex" => "display:flex",
+        "inline-flex" => "display:inline-flex",
+        "grid" => "display:grid",
+        "inline-grid" => "display:inline-grid",
+        "table" => "display:table",
+        "contents" => "display:contents",
+        "hidden" => "display:none",
+        "flex-row" => "flex-direction:row",
+        "flex-row-reverse" => "flex-direction:row-reverse",
+        "flex-col" => "flex-direction:column",
+        "flex-col-reverse" => "flex-direction:column-reverse",
+        "flex-wrap" => "flex-wrap:wrap",
+        "flex-nowrap" => "flex-wrap:nowrap",
+        "flex-1" => "flex:1 1 0%",
+        "flex-auto" => "flex:1 1 auto",
+        "flex-none" => "flex:none",
+        "grow" => "flex-grow:1",
+        "shrink-0" => "flex-shrink:0",
+        "static" => "position:static",
+        "relative" => "position:relative",
+        "absolute" => "position:absolute",
+        "fixed" => "position:fixed",
+        "sticky" => "position:sticky",
+        "overflow-hidden" => "overflow:hidden",
+        "overflow-auto" => "overflow:auto",
+        "overflow-scroll" => "overflow:scroll",
+        "overflow-x-auto" => "overflow-x:auto",
+        "overflow-y-auto" => "overflow-y:auto",
+        "truncate" => "overflow:hidden;text-overflow:ellipsis;white-space:nowrap",
+        "italic" => "font-style:italic",
+        "not-italic" => "font-style:normal",
+        "underline" => "text-decoration-line:underline",
+        "line-through" => "text-decoration-line:line-through",
+        "no-underline" => "text-decoration-line:none",
+        "uppercase" => "text-transform:uppercase",
+        "lowercase" => "text-transform:lowercase",
+        "capitalize" => "text-transform:capitalize",
+        "whitespace-nowrap" => "white-space:nowrap",
+        "break-words" => "overflow-wrap:break-word",
+        "list-disc" => "list-style-type:disc",
+        "list-decimal" => "list-style-type:decimal",
+        "list-none" => "list-style-type:none",
+        "cursor-pointer" => "cursor:pointer",
+        "cursor-not-allowed" => "cursor:not-allowed",
+        "pointer-events-none" => "pointer-events:none",
+        "select-none" => "user-select:none",
+        "mx-auto" => "margin-left:auto;margin-right:auto",
+        "border" => "border-width:1px",
+        "rounded" => "border-radius:0.25rem",
+        "shadow" => "box-shadow:0 1px 3px 0 rgb(0 0 0 / 0.1),0 1px 2px -1px rgb(0 0 0 / 0.1)",
+        "transition" => "transition-property:color,background-color,border-color,text-decoration-color,fill,stroke,opacity,box-shadow,transform;transition-timing-function:cubic-bezier(0.4,0,0.2,1);transition-duration:150ms",
+        "container" => "width:100%;max-width:80rem",
+        "sr-only" => "position:absolute;width:1px;height:1px;padding:0;margin:-1px;overflow:hidden;clip:rect(0,0,0,0);white-space:nowrap;border-width:0",
+        _ => return None,
+    };
+    Some(declaration)
+}
+
+fn spacing(value: &str) -> Option<String> {
+    if value == "px" {
+        return Some("1px".to_string());
+    }
+    if value == "0" {
+        return Some("0px".to_string());
+    }
+
+    let allowed = [
+        "0.5", "1", "1.5", "2", "2.5", "3", "3.5", "4", "5", "6", "7", "8", "9", "10", "11", "12", "14", "16",
+        "20", "24", "28", "32", "36", "40", "44", "48", "52", "56", "60", "64", "72", "80", "96",
+    ];
+    if !allowed.contains(&value) {
+        return None;
+    }
+
+    let number: f32 = value.parse().ok()?;
+    Some(format!("{}rem", number * 0.25))
+}
+
+fn sizing(value: &str, screen_unit: &str) -> Option<String> {
+    match value {
+        "auto" => Some("auto".to_string()),
+        "full" => Some("100%".to_string()),
+        "screen" => Some(format!("100{}", screen_unit)),
+        "min" => Some("min-content".to_string()),
+        "max" => Some("max-content".to_string()),
+        "fit" => Some("fit-content".to_string()),
+        _ => {
+            if let Some((numerator, denominator)) = value.split_once('/') {
+                let numerator: f32 = numerator.parse().ok()?;
+                let denominator: f32 = denominator.parse().ok()?;
+                if denominator == 0.0 || numerator >= denominator || ![2.0, 3.0, 4.0, 5.0, 6.0, 12.0].contains(&denominator) {
+                    return None;
+                }
+                let percent = (numerator / denominator * 100_000.0).round() / 1_000.0;
+                return Some(format!("{}%", percent));
+            }
+            spacing(value)
+        }
+    }
+}
+
+fn max_width(value: &str) -> Option<&'static str> {
+    Some(match value {
+        "xs" => "20rem",
+        "sm" => "24rem",
+        "md" => "28rem",
+        "lg" => "32rem",
+        "xl" => "36rem",
+        "2xl" => "42rem",
+        "3xl" => "48rem",
+        "4xl" => "56rem",
+        "5xl" => "64rem",
+        "6xl" => "72rem",
+        "7xl" => "80rem",
+        "full" => "100%",
+        "none" => "none",
+        "prose" => "65ch",
+        _ => return None,
+    })
+}
+
+fn text_utility(value: &str) -> Option<String> {
+    let size = match value {
+        "xs" => Some(("0.75rem", "1rem")),
+        "sm" => Some(("0.875rem", "1.25rem")),
+        "base" => Some(("1rem", "1.5rem")),
+        "lg" => Some(("1.125rem", "1.75rem")),
+        "xl" => Some(("1.25rem", "1.75rem")),
+        "2xl" => Some(("1.5rem", "2rem")),
+        "3xl" => Some(("1.875rem", "2.25rem")),
+        "4xl" => Some(("2.25rem", "2.5rem")),
+        "5xl" => Some(("3rem", "1")),
+        "6xl" => Some(("3.75rem", "1")),
+        "7xl" => Some(("4.5rem", "1")),
+        "8xl" => Some(("6rem", "1")),
+        "9xl" => Some(("8rem", "1")),
+        _ => None,
+    };
+
+    if let Some((font_size, line_height)) = size {
+        return Some(format!("font-size:{};line-height:{}", font_size, line_height));
+    }
+
+    match value {
+        "left" | "center" | "right" | "justify" => Some(format!("text-align:{}", value)),
+        _ => color(value).map(|c| format!("color:{}", c)),
+    }
+}
+
+fn border_utility(value: &str) -> Option<String> {
+    match value {
+        "0" | "2" | "4" | "8" => return Some(format!("border-width:{}px", value)),
+        "solid" | "dashed" | "dotted" | "double" | "none" => return Some(format!("border-style:{}", value)),
+        _ => {}
+    }
+
+    let sides = [("t", "top"), ("r", "right"), ("b", "bottom"), ("l", "left")];
+    for (short, side) in sides {
+        if value == short {
+            return Some(format!("border-{}-width:1px", side));
+        }
+        if let Some(width) = value.strip_prefix(short).and_then(|r| r.strip_prefix('-')) {
+            if ["0", "2", "4", "8"].contains(&width) {
+                return Some(format!("border-{}-width:{}px", side, width));
+            }
+        }
+    }
+
+    color(value).map(|c| format!("border-color:{}", c))
+}
+
+fn radius(value: &str) -> Option<&'static str> {
+    Some(match value {
+        "none" => "0px",
+        "sm" => "0.125rem",
+        "md" => "0.375rem",
+        "lg" => "0.5rem",
+        "xl" => "0.75rem",
+        "2xl" => "1rem",
+        "3xl" => "1.5rem",
+        "full" => "9999px",
+        _ => return None,
+    })
+}
+
+fn shadow(value: &str) -> Option<&'static str> {
+    Some(match value {
+        "sm" => "0 1px 2px 0 rgb(0 0 0 / 0.05)",
+        "md" => "0 4px 6px -1px rgb(0 0 0 / 0.1),0 2px 4px -2px rgb(0 0 0 / 0.1)",
+        "lg" => "0 10px 15px -3px rgb(0 0 0 / 0.1),0 4px 6px -4px rgb(0 0 0 / 0.1)",
+        "xl" => "0 20px 25px -5px rgb(0 0 0 / 0.1),0 8px 10px -6px rgb(0 0 0 / 0.1)",
+        "2xl" => "0 25px 50px -12px rgb(0 0 0 / 0.25)",
+        "inner" => "inset 0 2px 4px 0 rgb(0 0 0 / 0.05)",
+        "none" => "0 0 #0000",
+        _ => return None,
+    })
+}
+
+fn font_utility(value: &str) -> Option<String> {
+    let weight = match value {
+        "thin" => "100",
+        "extralight" => "200",
+        "light" => "300",
+        "normal" => "400",
+        "medium" => "500",
+        "semibold" => "600",
+        "bold" => "700",
+        "extrabold" => "800",
+        "black" => "900",
+        "sans" => return Some("font-family:ui-sans-serif,system-ui,sans-serif".to_string()),
+        "serif" => return Some("font-family:ui-serif,Georgia,Cambria,\"Times New Roman\",Times,serif".to_string()),
+        "mono" => return Some("font-family:ui-monospace,SFMono-Regular,Menlo,Monaco,Consolas,monospace".to_string()),
+        _ => return None,
+    };
+    Some(format!("font-weight:{}", weight))
+}
+
+fn leading(value: &str) -> Option<&'static str> {
+    Some(match value {
+        "none" => "1",
+        "tight" => "1.25",
+        "snug" => "1.375",
+        "normal" => "1.5",
+        "relaxed" => "1.625",
+        "loose" => "2",
+        _ => return None,
+    })
+}
+
+fn tracking(value: &str) -> Option<&'static str> {
+    Some(match value {
+        "tighter" => "-0.05em",
+        "tight" => "-0.025em",
+        "normal" => "0em",
+        "wide" => "0.025em",
+        "wider" => "0.05em",
+        "widest" => "0.1em",
+        _ => return None,
+    })
+}
+
+fn color(value: &str) -> Option<String> {
+    match value {
+        "white" => return Some("#fff".to_string()),
+        "black" => return Some("#000".to_string()),
+        "transparent" => return Some("transparent".to_string()),
+        "current" => return Some("currentColor".to_string()),
+        "inherit" => return Some("inherit".to_string()),
+        _ => {}
+    }
+
+    let (name, shade) = value.rsplit_once('-')?;
+    let (_, shades) = PALETTE.iter().find(|(palette_name, _)| *palette_name == name)?;
+    let index = SHADES.iter().position(|s| *s == shade)?;
+    Some(shades[index].to_string())
+}
+
+fn escape_class(class: &str) -> String {
+    let mut escaped = String::with_capacity(class.len());
+    for (i, c) in class.chars().enumerate() {
+        if i == 0 && c.is_ascii_digit() {
+            escaped.push_str(&format!("\\{:x} ", c as u32));
+        } else if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+            escaped.push(c);
+        } else {
+            escaped.push('\\');
+            escaped.push(c);
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn classes(names: &[&str]) -> BTreeSet<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    fn rules(names: &[&str]) -> String {
+        let css = generate(&classes(names)).css;
+        css.strip_prefix(PREFLIGHT).unwrap().to_string()
+    }
+
+    #[test]
+    fn spacing_scale_uses_quarter_rems() {
+        assert_eq!(declarations("p-4").as_deref(), Some("padding:1rem"));
+        assert_eq!(declarations("px-0.5").as_deref(), Some("padding-left:0.125rem;padding-right:0.125rem"));
+        assert_eq!(declarations("mt-px").as_deref(), Some("margin-top:1px"));
+        assert_eq!(declarations("gap-x-2").as_deref(), Some("column-gap:0.5rem"));
+        assert_eq!(declarations("m-13"), None);
+    }
+
+    #[test]
+    fn fractions_become_percentages() {
+        assert_eq!(declarations("w-1/2").as_deref(), Some("width:50%"));
+        assert_eq!(declarations("w-1/3").as_deref(), Some("width:33.333%"));
+        assert_eq!(declarations("w-3/2"), None);
+        assert_eq!(declarations("w-1/7"), None);
+    }
+
+    #[test]
+    fn negative_utilities_only_apply_to_margins() {
+        assert_eq!(declarations("-mt-2").as_deref(), Some("margin-top:-0.5rem"));
+        assert_eq!(declarations("-mx-4").as_deref(), Some("margin-left:-1rem;margin-right:-1rem"));
+        assert_eq!(declarations("-p-2"), None);
+        assert_eq!(declarations("-m-auto"), None);
+        assert_eq!(declarations("-w-4"), None);
+    }
+
+    #[test]
+    fn responsive_variants_are_wrapped_in_media_queries() {
+        assert_eq!(rules(&["sm:p-4"]), "@media (min-width:640px){.sm\\:p-4{padding:1rem}}");
+    }
+
+    #[test]
+    fn hover_variants_add_a_pseudo_class() {
+        assert_eq!(
+            rules(&["hover:bg-blue-500"]),
+            ".hover\\:bg-blue-500:hover{background-color:#3b82f6}"
+        );
+        assert_eq!(
+            rules(&["md:hover:underline"]),
+            "@media (min-width:768px){.md\\:hover\\:underline:hover{text-decoration-line:underline}}"
+        );
+    }
+
+    #[test]
+    fn selectors_are_escaped() {
+        assert_eq!(rules(&["w-1/2"]), ".w-1\\/2{width:50%}");
+        assert_eq!(rules(&["p-0.5"]), ".p-0\\.5{padding:0.125rem}");
+        assert_eq!(escape_class("2xl:p-4"), "\\32 xl\\:p-4");
+    }
+
+    #[test]
+    fn space_utilities_target_siblings() {
+        assert_eq!(
+            rules(&["space-y-2"]),
+            ".space-y-2>:not([hidden])~:not([hidden]){margin-top:0.5rem}"
+        );
+    }
+
+    #[test]
+    fn unknown_classes_are_skipped() {
+        let generated = generate(&classes(&["p-4", "btn-primary", "wobble:p-4", "text-teal-500"]));
+
+        assert_eq!(generated.generated, 1);
+        assert_eq!(generated.unknown, vec!["btn-primary", "text-teal-500", "wobble:p-4"]);
+        assert_eq!(generated.css, format!("{}.p-4{{padding:1rem}}", PREFLIGHT));
+    }
+}
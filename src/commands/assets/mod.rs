@@ -0,0 +1,927 @@
+use anyhow::Result;
+use base64::Engine;
+use clap::Subcommand;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256, Sha384};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+use walkdir::WalkDir;
+
+use crate::config::{find_config_file, GoaConfig};
+use crate::errors::{GoaError, GoaResult};
+use crate::utils;
+
+mod css;
+
+const CDN_ASSETS: [(&str, &str); 4] = [
+    ("tailwind", "tailwind.js"),
+    ("jquery", "jquery.js"),
+    ("alpine", "alpine.js"),
+    ("petiteVue", "petite-vue.js"),
+];
+
+#[derive(Subcommand)]
+pub enum AssetsCommands {
+    #[clap(about = "Download CDN assets into the static directory and serve them locally")]
+    Vendor {
+        #[clap(long, value_name = "DIR", help = "Read assets from a local mirror laid out as <host>/<path>")]
+        mirror: Option<String>,
+
+        #[clap(long, help = "Download assets again even if they are in the local cache")]
+        refresh: bool,
+    },
+
+    #[clap(about = "Compute Subresource Integrity hashes for CDN assets and check templates")]
+    Sri {
+        #[clap(long, help = "Scan layout and page templates for CDN tags without a matching integrity attribute")]
+        check: bool,
+
+        #[clap(long, value_name = "DIR", help = "Read assets from a local mirror laid out as <host>/<path>")]
+        mirror: Option<String>,
+
+        #[clap(long, help = "Download assets again even if they are in the local cache")]
+        refresh: bool,
+    },
+
+    #[clap(about = "Compile the Tailwind classes used in templates into a minified CSS file")]
+    Css {
+        #[clap(long, help = "Use the built-in class extractor even if a Tailwind binary is available")]
+        builtin: bool,
+
+        #[clap(long, help = "Leave the layout template unchanged instead of linking the compiled stylesheet")]
+        keep_layout: bool,
+    },
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct VendorManifest {
+    assets: BTreeMap<String, VendoredAsset>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct VendoredAsset {
+    source: String,
+    path: String,
+    integrity: String,
+}
+
+struct SriFinding {
+    line: usize,
+    message: String,
+    problem: bool,
+}
+
+enum AssetReference<'a> {
+    Asset(&'a str, &'a str),
+    Unknown,
+}
+
+pub fn handle_assets_command(command: AssetsCommands) -> Result<()> {
+    match command {
+        AssetsCommands::Vendor { mirror, refresh } => vendor_assets(mirror, refresh),
+        AssetsCommands::Sri { check: true, .. } => check_sri(),
+        AssetsCommands::Sri { mirror, refresh, .. } => compute_sri(mirror, refresh),
+        AssetsCommands::Css { builtin, keep_layout } => build_css(builtin, keep_layout),
+    }
+}
+
+fn vendor_assets(mirror: Option<String>, refresh: bool) -> Result<()> {
+    utils::log_step("Vendoring CDN assets");
+
+    let config_path = find_config_file()?;
+    let mirror = mirror.map(PathBuf::from);
+    let vendored = vendor_project(&config_path, mirror.as_deref(), refresh)?;
+
+    if vendored == 0 {
+        utils::log_warning("No CDN assets were vendored");
+    } else {
+        utils::log_success(&format!("Vendored {} asset(s); useCDN disabled and CDN paths rewritten", vendored));
+    }
+    Ok(())
+}
+
+fn vendor_project(config_path: &Path, mirror: Option<&Path>, refresh: bool) -> Result<usize> {
+    let mut goa_config = GoaConfig::load(config_path)?;
+    let project_dir = config_path.parent().unwrap().to_path_buf();
+
+    let vendor_dir = project_dir.join(&goa_config.directories.static_dir).join("vendor");
+    let vendor_url_prefix = format!("{}/vendor", goa_config.get_static_url_prefix());
+    let manifest_path = vendor_dir.join("manifest.json");
+    let mut manifest = load_manifest(&manifest_path)?;
+
+    let configured_assets: Vec<(&str, String)> = goa_config.cdn.assets()
+        .into_iter()
+        .map(|(key, url)| (key, url.to_string()))
+        .collect();
+    let mut vendored = 0;
+
+    for (key, file_name) in CDN_ASSETS {
+        let configured = match configured_assets.iter().find(|(k, _)| *k == key) {
+            Some((_, value)) if !value.trim().is_empty() => value.clone(),
+            _ => {
+                utils::log_info(&format!("Skipping {} (not configured)", key));
+                continue;
+            }
+        };
+
+        let source = if is_remote_url(&configured) {
+            configured
+        } else if let Some(existing) = manifest.assets.get(key) {
+            existing.source.clone()
+        } else {
+            utils::log_warning(&format!("Skipping {} ({} is not a CDN URL and has no vendored source)", key, configured));
+            continue;
+        };
+
+        utils::log_step(&format!("Fetching {} from {}", key, source));
+        let contents = fetch_asset(&source, mirror, refresh)?;
+
+        utils::ensure_directory_exists(&vendor_dir)?;
+        fs::write(vendor_dir.join(file_name), &contents)
+            .map_err(GoaError::Io)?;
+
+        let local_path = format!("{}/{}", vendor_url_prefix, file_name);
+        let integrity = integrity_hash(&contents);
+        utils::log_success(&format!("Vendored {} as {} ({})", key, local_path, integrity));
+
+        goa_config.cdn.set_asset(key, local_path.clone());
+        goa_config.cdn.integrity.insert(key.to_string(), integrity.clone());
+        manifest.assets.insert(key.to_string(), VendoredAsset {
+            source,
+            path: local_path,
+            integrity,
+        });
+        vendored += 1;
+    }
+
+    if vendored == 0 {
+        return Ok(0);
+    }
+
+    goa_config.cdn.use_cdn = false;
+
+    let manifest_json = serde_json::to_string_pretty(&manifest)
+        .map_err(GoaError::Json)?;
+    utils::write_file(&manifest_path, &manifest_json)?;
+
+    goa_config.save(config_path)?;
+
+    Ok(vendored)
+}
+
+fn compute_sri(mirror: Option<String>, refresh: bool) -> Result<()> {
+    utils::log_step("Computing Subresource Integrity hashes for CDN assets");
+
+    let config_path = find_config_file()?;
+    let mut goa_config = GoaConfig::load(&config_path)?;
+    let project_dir = config_path.parent().unwrap().to_path_buf();
+    let mirror = mirror.map(PathBuf::from);
+
+    let mut integrity_map = BTreeMap::new();
+    for (key, url) in goa_config.cdn.assets() {
+        if url.trim().is_empty() {
+            utils::log_info(&format!("Skipping {} (not configured)", key));
+            continue;
+        }
+
+        let contents = if is_remote_url(url) {
+            fetch_asset(url, mirror.as_deref(), refresh)?
+        } else {
+            let local_path = resolve_local_asset(&project_dir, &goa_config, url);
+            fs::read(&local_path).map_err(|e| GoaError::InvalidPath(format!(
+                "Failed to read {} at {}: {}",
+                key,
+                local_path.display(),
+                e
+            )))?
+        };
+
+        let integrity = integrity_hash(&contents);
+        utils::log_success(&format!("{}: {}", key, integrity));
+        integrity_map.insert(key.to_string(), integrity);
+    }
+
+    goa_config.cdn.integrity = integrity_map;
+    goa_config.save(&config_path)?;
+
+    utils::log_success("Integrity hashes saved to cdn.integrity in config.json");
+    Ok(())
+}
+
+fn check_sri() -> Result<()> {
+    utils::log_step("Checking templates for CDN references without integrity attributes");
+
+    let config_path = find_config_file()?;
+    let goa_config = GoaConfig::load(&config_path)?;
+    let project_dir = config_path.parent().unwrap().to_path_buf();
+
+    let assets = goa_config.cdn.assets();
+    let mut checked = 0;
+    let mut problems = 0;
+
+    for file in collect_template_files(&project_dir, &goa_config) {
+        let content = match fs::read_to_string(&file) {
+            Ok(content) => content,
+            Err(_) => continue,
+        };
+        let display_path = file.strip_prefix(&project_dir).unwrap_or(&file).display().to_string();
+
+        let (file_checked, findings) = scan_sri(&content, &assets, &goa_config.cdn.integrity);
+        checked += file_checked;
+        for finding in findings {
+            if finding.problem {
+                problems += 1;
+            }
+            utils::log_warning(&format!("{}:{}: {}", display_path, finding.line, finding.message));
+        }
+    }
+
+    if problems > 0 {
+        utils::log_info("Run `goa assets sri` to compute hashes, then add integrity=\"...\" crossorigin=\"anonymous\" to the tags above");
+        return Err(GoaError::Other(format!("{} CDN reference(s) without a matching integrity attribute", problems)).into());
+    }
+
+    if checked == 0 {
+        utils::log_warning("No CDN references to configured assets were found in the layout or page templates");
+        return Ok(());
+    }
+
+    utils::log_success(&format!("All {} CDN reference(s) have matching integrity attributes", checked));
+    Ok(())
+}
+
+fn scan_sri(content: &str, assets: &[(&str, &str)], expected: &BTreeMap<String, String>) -> (usize, Vec<SriFinding>) {
+    let tag_regex = Regex::new(r"(?is)<(script|link)\b[^>]*>").unwrap();
+    let attr_regex = Regex::new(r#"(?i)\b(src|href|integrity|crossorigin)\s*=\s*(?:"([^"]*)"|'([^']*)')"#).unwrap();
+
+    let mut checked = 0;
+    let mut findings = Vec::new();
+
+    for tag in tag_regex.find_iter(content) {
+        let mut target = None;
+        let mut integrity = None;
+        let mut has_crossorigin = tag.as_str().to_lowercase().contains("crossorigin");
+
+        for attr in attr_regex.captures_iter(tag.as_str()) {
+            let value = attr.get(2).or_else(|| attr.get(3)).map(|m| m.as_str().to_string()).unwrap_or_default();
+            match attr[1].to_lowercase().as_str() {
+                "src" | "href" => target = Some(value),
+                "integrity" => integrity = Some(value),
+                _ => has_crossorigin = true,
+            }
+        }
+
+        let target = match target {
+            Some(target) => target,
+            None => continue,
+        };
+        let line = content[..tag.start()].matches('\n').count() + 1;
+
+        let (key, url) = match referenced_asset(&target, assets) {
+            Some(AssetReference::Asset(key, url)) => (key, url),
+            Some(AssetReference::Unknown) => {
+                findings.push(SriFinding {
+                    line,
+                    message: format!("{} does not name a configured CDN asset, so its integrity cannot be checked", target),
+                    problem: true,
+                });
+                continue;
+            }
+            None => continue,
+        };
+
+        checked += 1;
+        let expected = expected.get(key);
+        let templated_integrity = integrity.as_deref().map(|value| value.contains("{{")).unwrap_or(false);
+
+        match (&integrity, expected) {
+            (None, _) => findings.push(SriFinding {
+                line,
+                message: format!("{} is loaded without an integrity attribute", target),
+                problem: true,
+            }),
+            (Some(actual), Some(expected)) if !templated_integrity && !actual.split_whitespace().any(|hash| hash == expected) => {
+                findings.push(SriFinding {
+                    line,
+                    message: format!("integrity for {} does not match cdn.integrity.{} ({})", target, key, expected),
+                    problem: true,
+                });
+            }
+            _ => {}
+        }
+
+        if integrity.is_some() && is_remote_url(url) && !has_crossorigin {
+            findings.push(SriFinding {
+                line,
+                message: format!("{} has an integrity attribute but no crossorigin attribute", target),
+                problem: false,
+            });
+        }
+    }
+
+    (checked, findings)
+}
+
+fn referenced_asset<'a>(target: &str, assets: &[(&'a str, &'a str)]) -> Option<AssetReference<'a>> {
+    let configured = |key: &str| {
+        assets.iter().find(|(k, url)| k.eq_ignore_ascii_case(key) && !url.trim().is_empty()).copied()
+    };
+
+    if !target.contains("{{") {
+        return assets
+            .iter()
+            .find(|(_, url)| !url.trim().is_empty() && *url == target)
+            .map(|(key, url)| AssetReference::Asset(key, url));
+    }
+
+    let action_regex = Regex::new(r"\{\{-?\s*([^\s}|]*)").unwrap();
+    for action in action_regex.captures_iter(target) {
+        let field = action[1].rsplit('.').next().unwrap_or_default().replace('_', "");
+        if let Some((key, url)) = configured(&field) {
+            return Some(AssetReference::Asset(key, url));
+        }
+        if assets.iter().any(|(key, _)| key.eq_ignore_ascii_case(&field)) {
+            return None;
+        }
+        if action[1].to_lowercase().contains("cdn") {
+            return Some(AssetReference::Unknown);
+        }
+    }
+
+    None
+}
+
+fn build_css(builtin: bool, keep_layout: bool) -> Result<()> {
+    utils::log_step("Compiling Tailwind CSS");
+
+    let config_path = find_config_file()?;
+    let goa_config = GoaConfig::load(&config_path)?;
+    let project_dir = config_path.parent().unwrap().to_path_buf();
+
+    let output_path = if builtin {
+        compile_css_builtin(&project_dir, &goa_config)?
+    } else {
+        compile_css(&project_dir, &goa_config)?
+    };
+
+    if keep_layout {
+        hint_unlinked_css(&project_dir, &goa_config);
+    } else {
+        use_compiled_css(&project_dir, &goa_config)?;
+    }
+    utils::log_info(&format!("{} is rebuilt by `goa dev` and `goa project build` from now on", output_path.display()));
+    Ok(())
+}
+
+fn compiled_css_url(config: &GoaConfig) -> String {
+    format!("{}/css/tailwind.css", config.get_static_url_prefix())
+}
+
+fn use_compiled_css(project_dir: &Path, config: &GoaConfig) -> Result<()> {
+    let stylesheet = compiled_css_url(config);
+    let layout_path = project_dir.join(&config.directories.layout_path);
+
+    let layout = match fs::read_to_string(&layout_path) {
+        Ok(layout) => layout,
+        Err(_) => {
+            utils::log_info(&format!(
+                "Reference it from your layout with <link rel=\"stylesheet\" href=\"{}\">",
+                stylesheet
+            ));
+            return Ok(());
+        }
+    };
+
+    let updated = match link_compiled_css(&layout, &stylesheet) {
+        Some(updated) => updated,
+        None => return Ok(()),
+    };
+
+    let prompt = format!(
+        "Replace the Tailwind CDN script in {} with a link to {}?",
+        config.directories.layout_path, stylesheet
+    );
+    if console::user_attended() && !utils::prompt_confirm(prompt, true)? {
+        hint_unlinked_css(project_dir, config);
+        return Ok(());
+    }
+
+    utils::write_file(&layout_path, &updated)?;
+    utils::log_success(&format!(
+        "Replaced the Tailwind CDN script in {} with a link to {}",
+        config.directories.layout_path, stylesheet
+    ));
+    Ok(())
+}
+
+pub fn hint_unlinked_css(project_dir: &Path, config: &GoaConfig) {
+    let stylesheet = compiled_css_url(config);
+    let linked = fs::read_to_string(project_dir.join(&config.directories.layout_path))
+        .map(|layout| layout.contains(&stylesheet))
+        .unwrap_or(false);
+
+    if !linked {
+        utils::log_info(&format!(
+            "{} does not link the compiled CSS yet; add <link rel=\"stylesheet\" href=\"{}\"> or run `goa assets css`",
+            config.directories.layout_path, stylesheet
+        ));
+    }
+}
+
+fn link_compiled_css(layout: &str, stylesheet: &str) -> Option<String> {
+    let script_regex = Regex::new(r#"(?is)[ \t]*<script\b[^>]*\bsrc\s*=\s*["'][^"']*tailwind[^"']*["'][^>]*>\s*</script>[ \t]*\r?\n?"#).unwrap();
+    let mut updated = script_regex.replace_all(layout, "").into_owned();
+
+    if !updated.contains(stylesheet) {
+        let link = format!("<link rel=\"stylesheet\" href=\"{}\">", stylesheet);
+        match Regex::new(r"(?i)</head>").unwrap().find(&updated).map(|head| head.start()) {
+            Some(index) => updated.insert_str(index, &format!("    {}\n", link)),
+            None => updated.insert_str(0, &format!("{}\n", link)),
+        }
+    }
+
+    (updated != layout).then_some(updated)
+}
+
+pub fn css_output_path(project_dir: &Path, config: &GoaConfig) -> PathBuf {
+    project_dir.join(&config.directories.static_dir).join("css").join("tailwind.css")
+}
+
+pub fn compile_css(project_dir: &Path, config: &GoaConfig) -> Result<PathBuf> {
+    let binary = find_tailwind_binary(project_dir);
+    let binary = match binary {
+        Some(binary) => binary,
+        None => return compile_css_builtin(project_dir, config),
+    };
+
+    let output_path = css_output_path(project_dir, config);
+    if let Some(parent) = output_path.parent() {
+        utils::ensure_directory_exists(parent)?;
+    }
+
+    let major_version = tailwind_major_version(&binary);
+    let template_dirs = [&config.directories.app_dir, &config.directories.component_dir];
+
+    let custom_input = project_dir.join("tailwind.input.css");
+    let generated_input = std::env::temp_dir().join(format!("goa-tailwind-{}.css", std::process::id()));
+    let input_path = if custom_input.exists() {
+        custom_input
+    } else {
+        let input = if major_version >= 4 {
+            let sources: String = template_dirs
+                .iter()
+                .map(|dir| format!("@source \"{}\";\n", project_dir.join(dir).display().to_string().replace('\\', "/")))
+                .collect();
+            format!("@import \"tailwindcss\";\n{}", sources)
+        } else {
+            "@tailwind base;\n@tailwind components;\n@tailwind utilities;\n".to_string()
+        };
+        fs::write(&generated_input, input).map_err(GoaError::Io)?;
+        generated_input.clone()
+    };
+
+    utils::log_step(&format!("Running {} (Tailwind v{})", binary.display(), major_version));
+    let mut tailwind = Command::new(&binary);
+    tailwind
+        .arg("-i").arg(&input_path)
+        .arg("-o").arg(&output_path)
+        .arg("--minify")
+        .current_dir(project_dir);
+    if major_version < 4 {
+        let content = template_dirs
+            .iter()
+            .map(|dir| format!("{}/**/*.html", dir.trim_end_matches('/')))
+            .collect::<Vec<_>>()
+            .join(",");
+        tailwind.args(["--content", &content]);
+    }
+    utils::log_command(&tailwind);
+    let output = tailwind.output();
+
+    fs::remove_file(&generated_input).ok();
+
+    let output = output.map_err(|e| GoaError::Other(format!("Failed to run {}: {}", binary.display(), e)))?;
+    if !output.status.success() {
+        let error = String::from_utf8_lossy(&output.stderr);
+        return Err(GoaError::Other(format!("Tailwind compilation failed: {}", error.trim())).into());
+    }
+
+    utils::log_success(&format!("Compiled CSS with Tailwind into {}", output_path.display()));
+    Ok(output_path)
+}
+
+fn compile_css_builtin(project_dir: &Path, config: &GoaConfig) -> Result<PathBuf> {
+    let class_regex = Regex::new(r#"(?i)\bclass\s*=\s*(?:"([^"]*)"|'([^']*)')"#).unwrap();
+    let action_regex = Regex::new(r"\{\{.*?\}\}").unwrap();
+
+    let mut classes = std::collections::BTreeSet::new();
+    for file in collect_template_files(project_dir, config) {
+        let content = match fs::read_to_string(&file) {
+            Ok(content) => content,
+            Err(_) => continue,
+        };
+
+        for captures in class_regex.captures_iter(&content) {
+            let value = captures.get(1).or_else(|| captures.get(2)).map(|m| m.as_str()).unwrap_or_default();
+            let value = action_regex.replace_all(value, " ");
+            for class in value.split_whitespace() {
+                if class.chars().all(|c| c.is_ascii_alphanumeric() || "-_:/.".contains(c)) {
+                    classes.insert(class.to_string());
+                }
+            }
+        }
+    }
+
+    let generated = css::generate(&classes);
+
+    let output_path = css_output_path(project_dir, config);
+    utils::write_file(&output_path, &generated.css)?;
+
+    utils::log_success(&format!(
+        "Compiled {} of {} class(es) with the built-in generator",
+        generated.generated,
+        classes.len()
+    ));
+
+    if !generated.unknown.is_empty() {
+        utils::log_warning(&format!(
+            "{} class(es) are not supported by the built-in generator: {}",
+            generated.unknown.len(),
+            generated.unknown.join(" ")
+        ));
+        utils::log_info("Install the standalone Tailwind CLI as ./tailwindcss or on your PATH for full coverage");
+    }
+
+    Ok(output_path)
+}
+
+fn tailwind_major_version(binary: &Path) -> u32 {
+    let output = Command::new(binary).arg("--help").output();
+    let version = output.ok().and_then(|output| {
+        let text = format!("{}{}", String::from_utf8_lossy(&output.stdout), String::from_utf8_lossy(&output.stderr));
+        parse_tailwind_major_version(&text)
+    });
+
+    version.unwrap_or_else(|| {
+        utils::log_debug(&format!("Could not read the version of {}; assuming Tailwind v3", binary.display()));
+        3
+    })
+}
+
+fn parse_tailwind_major_version(help: &str) -> Option<u32> {
+    Regex::new(r"(?i)tailwindcss v(\d+)\.")
+        .unwrap()
+        .captures(help)
+        .and_then(|captures| captures[1].parse().ok())
+}
+
+fn find_tailwind_binary(project_dir: &Path) -> Option<PathBuf> {
+    let name = if cfg!(windows) { "tailwindcss.exe" } else { "tailwindcss" };
+
+    let local = project_dir.join(name);
+    if local.is_file() {
+        return Some(local);
+    }
+
+    if utils::is_command_available("tailwindcss") {
+        return Some(PathBuf::from("tailwindcss"));
+    }
+
+    None
+}
+
+pub fn collect_template_files(project_dir: &Path, config: &GoaConfig) -> Vec<PathBuf> {
+    let mut files = std::collections::BTreeSet::new();
+
+    for dir in [&config.directories.app_dir, &config.directories.component_dir] {
+        for entry in WalkDir::new(project_dir.join(dir)).into_iter().filter_map(|e| e.ok()) {
+            if entry.file_type().is_file() && entry.path().extension().map(|ext| ext == "html").unwrap_or(false) {
+                files.insert(entry.path().to_path_buf());
+            }
+        }
+    }
+
+    let layout_path = project_dir.join(&config.directories.layout_path);
+    if layout_path.exists() {
+        files.insert(layout_path);
+    }
+
+    files.into_iter().collect()
+}
+
+fn resolve_local_asset(project_dir: &Path, config: &GoaConfig, path: &str) -> PathBuf {
+    let static_prefix = config.get_static_url_prefix();
+    let relative = match path.strip_prefix(&static_prefix) {
+        Some(rest) => format!("{}/{}", config.directories.static_dir, rest.trim_start_matches('/')),
+        None => path.trim_start_matches('/').to_string(),
+    };
+
+    project_dir.join(relative)
+}
+
+fn integrity_hash(contents: &[u8]) -> String {
+    let digest = Sha384::digest(contents);
+    format!("sha384-{}", base64::engine::general_purpose::STANDARD.encode(digest))
+}
+
+fn is_remote_url(value: &str) -> bool {
+    value.starts_with("https://") || value.starts_with("http://") || value.starts_with("//")
+}
+
+fn fetch_asset(url: &str, mirror: Option<&Path>, refresh: bool) -> GoaResult<Vec<u8>> {
+    if let Some(mirror) = mirror {
+        let mirrored = mirror.join(mirror_relative_path(url));
+        return fs::read(&mirrored).map_err(|e| GoaError::InvalidPath(format!(
+            "Asset {} not found in mirror at {}: {}",
+            url,
+            mirrored.display(),
+            e
+        )));
+    }
+
+    let cache_path = asset_cache_path(url);
+    if !refresh {
+        if let Ok(contents) = fs::read(&cache_path) {
+            utils::log_info(&format!("Using cached copy of {}", url));
+            return Ok(contents);
+        }
+    }
+
+    let download_url = if url.starts_with("//") { format!("https:{}", url) } else { url.to_string() };
+
+    let client = reqwest::blocking::Client::builder()
+        .timeout(Duration::from_secs(30))
+        .build()
+        .map_err(|e| GoaError::Other(format!("Failed to create HTTP client: {}", e)))?;
+
+    let response = client.get(&download_url).send()
+        .map_err(|e| GoaError::Other(format!("Failed to download {}: {}", url, e)))?;
+
+    if !response.status().is_success() {
+        return Err(GoaError::Other(format!("Failed to download {}: server responded with {}", url, response.status())));
+    }
+
+    let contents = response.bytes()
+        .map_err(|e| GoaError::Other(format!("Failed to download {}: {}", url, e)))?
+        .to_vec();
+
+    if let Some(parent) = cache_path.parent() {
+        fs::create_dir_all(parent).ok();
+    }
+    fs::write(&cache_path, &contents).ok();
+
+    Ok(contents)
+}
+
+fn mirror_relative_path(url: &str) -> PathBuf {
+    let without_scheme = url.split_once("//").map(|(_, rest)| rest).unwrap_or(url);
+    let without_query = without_scheme.split(['?', '#']).next().unwrap_or(without_scheme);
+
+    let mut path = PathBuf::new();
+    for part in without_query.split('/').filter(|s| !s.is_empty() && *s != "..") {
+        path.push(part);
+    }
+
+    if without_query.ends_with('/') || !without_query.contains('/') {
+        path.push("index");
+    }
+
+    path
+}
+
+fn asset_cache_path(url: &str) -> PathBuf {
+    let mut path = dirs::cache_dir().unwrap_or_else(|| PathBuf::from("."));
+    path.push("goa-cli");
+    path.push("vendor");
+
+    let digest = Sha256::digest(url.as_bytes());
+    let name: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
+    path.push(name);
+    path
+}
+
+fn load_manifest(path: &Path) -> Result<VendorManifest> {
+    if !path.exists() {
+        return Ok(VendorManifest::default());
+    }
+
+    let content = fs::read_to_string(path)
+        .map_err(GoaError::Io)?;
+
+    serde_json::from_str(&content)
+        .map_err(|e| GoaError::Configuration(format!("Failed to parse vendor manifest {}: {}", path.display(), e)).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STYLESHEET: &str = "/static/css/tailwind.css";
+
+    fn assets() -> Vec<(&'static str, &'static str)> {
+        vec![
+            ("tailwind", "https://cdn.tailwindcss.com"),
+            ("jquery", ""),
+            ("alpine", "https://unpkg.com/alpinejs@3/dist/cdn.min.js"),
+            ("petiteVue", "https://unpkg.com/petite-vue"),
+        ]
+    }
+
+    fn integrity() -> BTreeMap<String, String> {
+        BTreeMap::from([("tailwind".to_string(), "sha384-abc".to_string())])
+    }
+
+    fn problems(findings: &[SriFinding]) -> Vec<(usize, &str)> {
+        findings.iter().filter(|f| f.problem).map(|f| (f.line, f.message.as_str())).collect()
+    }
+
+    #[test]
+    fn sri_check_flags_literal_urls_without_matching_integrity() {
+        let content = "<head>\n<script src=\"https://cdn.tailwindcss.com\"></script>\n<script src=\"https://cdn.tailwindcss.com\" integrity=\"sha384-old\" crossorigin=\"anonymous\"></script>\n<script src=\"https://cdn.tailwindcss.com\" integrity=\"sha384-abc\" crossorigin=\"anonymous\"></script>\n<script src=\"/static/app.js\"></script>\n</head>";
+
+        let (checked, findings) = scan_sri(content, &assets(), &integrity());
+
+        assert_eq!(checked, 3);
+        assert_eq!(problems(&findings), vec![
+            (2, "https://cdn.tailwindcss.com is loaded without an integrity attribute"),
+            (3, "integrity for https://cdn.tailwindcss.com does not match cdn.integrity.tailwind (sha384-abc)"),
+        ]);
+    }
+
+    #[test]
+    fn sri_check_resolves_templated_cdn_references() {
+        let content = "<script src=\"{{.Config.CDN.Tailwind}}\"></script>\n<script src='{{ .Config.CDN.PetiteVue }}' integrity='{{.Config.CDN.Integrity.petiteVue}}' crossorigin></script>\n<script src=\"{{.Config.CDN.JQuery}}\"></script>\n<link rel=\"stylesheet\" href=\"{{.StaticPrefix}}/app.css\">";
+
+        let (checked, findings) = scan_sri(content, &assets(), &integrity());
+
+        assert_eq!(checked, 2);
+        assert_eq!(problems(&findings), vec![
+            (1, "{{.Config.CDN.Tailwind}} is loaded without an integrity attribute"),
+        ]);
+    }
+
+    #[test]
+    fn sri_check_reports_templated_cdn_references_it_cannot_resolve() {
+        let content = "<script src=\"{{.Config.CDN.HTMX}}\"></script>";
+
+        let (checked, findings) = scan_sri(content, &assets(), &integrity());
+
+        assert_eq!(checked, 0);
+        assert_eq!(problems(&findings).len(), 1);
+    }
+
+    #[test]
+    fn sri_check_warns_about_missing_crossorigin() {
+        let content = "<script src=\"https://cdn.tailwindcss.com\" integrity=\"sha384-abc\"></script>";
+
+        let (_, findings) = scan_sri(content, &assets(), &integrity());
+
+        assert_eq!(findings.len(), 1);
+        assert!(!findings[0].problem);
+        assert!(findings[0].message.contains("no crossorigin attribute"));
+    }
+
+    fn vendor_fixture() -> (tempfile::TempDir, PathBuf, PathBuf) {
+        let dir = tempfile::tempdir().unwrap();
+        let config_path = dir.path().join("project").join("config.json");
+        fs::create_dir_all(config_path.parent().unwrap()).unwrap();
+        fs::write(&config_path, r#"{
+  "directories": { "staticDir": "static" },
+  "cdn": {
+    "useCDN": true,
+    "tailwind": "https://cdn.tailwindcss.com",
+    "jquery": "",
+    "alpine": "https://unpkg.com/alpinejs@3/dist/cdn.min.js?module",
+    "petiteVue": "/static/js/petite-vue.js"
+  }
+}"#).unwrap();
+
+        let mirror = dir.path().join("mirror");
+        fs::create_dir_all(mirror.join("cdn.tailwindcss.com")).unwrap();
+        fs::create_dir_all(mirror.join("unpkg.com/alpinejs@3/dist")).unwrap();
+        fs::write(mirror.join("cdn.tailwindcss.com/index"), "tailwind()").unwrap();
+        fs::write(mirror.join("unpkg.com/alpinejs@3/dist/cdn.min.js"), "alpine()").unwrap();
+
+        (dir, config_path, mirror)
+    }
+
+    #[test]
+    fn vendor_copies_assets_and_points_the_config_at_them() {
+        let (_dir, config_path, mirror) = vendor_fixture();
+        let project_dir = config_path.parent().unwrap();
+
+        assert_eq!(vendor_project(&config_path, Some(&mirror), false).unwrap(), 2);
+
+        let vendor_dir = project_dir.join("static/vendor");
+        assert_eq!(fs::read_to_string(vendor_dir.join("tailwind.js")).unwrap(), "tailwind()");
+        assert_eq!(fs::read_to_string(vendor_dir.join("alpine.js")).unwrap(), "alpine()");
+        assert!(!vendor_dir.join("jquery.js").exists());
+        assert!(!vendor_dir.join("petite-vue.js").exists());
+
+        let config = GoaConfig::load(&config_path).unwrap();
+        assert!(!config.cdn.use_cdn);
+        assert_eq!(config.cdn.tailwind, "/static/vendor/tailwind.js");
+        assert_eq!(config.cdn.alpine, "/static/vendor/alpine.js");
+        assert_eq!(config.cdn.jquery, "");
+        assert_eq!(config.cdn.petite_vue, "/static/js/petite-vue.js");
+        assert_eq!(config.cdn.integrity["tailwind"], integrity_hash(b"tailwind()"));
+
+        let manifest = load_manifest(&vendor_dir.join("manifest.json")).unwrap();
+        assert_eq!(manifest.assets["alpine"].source, "https://unpkg.com/alpinejs@3/dist/cdn.min.js?module");
+        assert_eq!(manifest.assets["alpine"].path, "/static/vendor/alpine.js");
+        assert_eq!(manifest.assets["alpine"].integrity, integrity_hash(b"alpine()"));
+    }
+
+    #[test]
+    fn vendor_again_uses_the_sources_from_the_manifest() {
+        let (_dir, config_path, mirror) = vendor_fixture();
+        vendor_project(&config_path, Some(&mirror), false).unwrap();
+
+        fs::write(mirror.join("cdn.tailwindcss.com/index"), "tailwind(2)").unwrap();
+        assert_eq!(vendor_project(&config_path, Some(&mirror), false).unwrap(), 2);
+
+        let project_dir = config_path.parent().unwrap();
+        assert_eq!(fs::read_to_string(project_dir.join("static/vendor/tailwind.js")).unwrap(), "tailwind(2)");
+        let config = GoaConfig::load(&config_path).unwrap();
+        assert_eq!(config.cdn.integrity["tailwind"], integrity_hash(b"tailwind(2)"));
+    }
+
+    #[test]
+    fn vendor_fails_when_the_mirror_lacks_an_asset() {
+        let (_dir, config_path, mirror) = vendor_fixture();
+        fs::remove_file(mirror.join("unpkg.com/alpinejs@3/dist/cdn.min.js")).unwrap();
+
+        let error = vendor_project(&config_path, Some(&mirror), false).unwrap_err();
+        assert!(error.to_string().contains("not found in mirror"));
+        assert!(GoaConfig::load(&config_path).unwrap().cdn.use_cdn);
+    }
+
+    #[test]
+    fn invalid_vendor_manifest_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("manifest.json");
+
+        assert!(load_manifest(&path).unwrap().assets.is_empty());
+        fs::write(&path, "{ not json").unwrap();
+        assert!(load_manifest(&path).is_err());
+    }
+
+    #[test]
+    fn integrity_hash_is_base64_sha384() {
+        assert_eq!(integrity_hash(b""), "sha384-OLBgp1GsljhM2TJ+sbHjaiH9txEUvgdDTAzHv2P24donTt6/529l+9Ua0vFImLlb");
+        assert_eq!(integrity_hash(b"alert(1)"), "sha384-HT2E9NfWiuQ/w1PRai+hTyqW16NIoCGA/m8VQDUopfAtcz6YQjtsMmQd5uRbVDpW");
+    }
+
+    #[test]
+    fn mirror_relative_path_follows_host_and_path() {
+        assert_eq!(
+            mirror_relative_path("https://unpkg.com/alpinejs@3/dist/cdn.min.js?v=1#x"),
+            PathBuf::from("unpkg.com/alpinejs@3/dist/cdn.min.js")
+        );
+        assert_eq!(mirror_relative_path("//cdn.tailwindcss.com"), PathBuf::from("cdn.tailwindcss.com/index"));
+        assert_eq!(mirror_relative_path("https://unpkg.com/petite-vue/"), PathBuf::from("unpkg.com/petite-vue/index"));
+        assert_eq!(mirror_relative_path("https://evil.com/../../etc/passwd"), PathBuf::from("evil.com/etc/passwd"));
+    }
+
+    #[test]
+    fn replaces_the_tailwind_script_with_the_stylesheet() {
+        let layout = "<html>\n<head>\n    <title>App</title>\n    <script src=\"https://cdn.tailwindcss.com\"></script>\n</head>\n</html>\n";
+
+        let updated = link_compiled_css(layout, STYLESHEET).unwrap();
+
+        assert_eq!(
+            updated,
+            "<html>\n<head>\n    <title>App</title>\n    <link rel=\"stylesheet\" href=\"/static/css/tailwind.css\">\n</head>\n</html>\n"
+        );
+    }
+
+    #[test]
+    fn removes_vendored_and_templated_tailwind_scripts() {
+        let layout = "<head>\n<script src=\"/static/vendor/tailwind.js\"></script>\n<script src='{{.Config.CDN.Tailwind}}'></script>\n<script src=\"/static/app.js\"></script>\n</head>";
+
+        let updated = link_compiled_css(layout, STYLESHEET).unwrap();
+
+        assert!(!updated.contains("tailwind.js"));
+        assert!(!updated.contains("CDN.Tailwind"));
+        assert!(updated.contains("<script src=\"/static/app.js\"></script>"));
+        assert!(updated.contains(STYLESHEET));
+    }
+
+    #[test]
+    fn reads_the_major_version_from_tailwind_help() {
+        assert_eq!(parse_tailwind_major_version("\ntailwindcss v3.4.17\n\nUsage:\n   tailwindcss build [options]"), Some(3));
+        assert_eq!(parse_tailwind_major_version("≈ tailwindcss v4.1.3\n\nUsage:\n  tailwindcss [--input input.css]"), Some(4));
+        assert_eq!(parse_tailwind_major_version("Usage: tailwindcss [options]"), None);
+    }
+
+    #[test]
+    fn leaves_a_layout_that_already_links_the_stylesheet_alone() {
+        let layout = "<head>\n    <link rel=\"stylesheet\" href=\"/static/css/tailwind.css\">\n</head>";
+
+        assert_eq!(link_compiled_css(layout, STYLESHEET), None);
+    }
+}
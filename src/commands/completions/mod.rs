@@ -0,0 +1,115 @@
+use anyhow::Result;
+use clap::{Args, Command};
+use clap_complete::env::Shells;
+use clap_complete::CompletionCandidate;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use walkdir::WalkDir;
+
+use crate::commands::project::collect_page_routes;
+use crate::config::{find_config_file, GoaConfig};
+use crate::errors::GoaError;
+use crate::utils;
+
+pub const COMPLETE_ENV: &str = "COMPLETE";
+
+const SHELLS: [&str; 5] = ["bash", "zsh", "fish", "powershell", "elvish"];
+
+#[derive(Args)]
+pub struct CompletionsArgs {
+    #[clap(value_parser = SHELLS, help = "Shell to generate the completion script for")]
+    shell: String,
+}
+
+#[derive(Args)]
+pub struct ManArgs {
+    #[clap(long = "out-dir", short = 'o', value_name = "DIR", help = "Write a man page for every command into this directory instead of printing goa.1")]
+    output: Option<String>,
+}
+
+pub fn handle_completions_command(args: CompletionsArgs, command: Command) -> Result<()> {
+    let name = command.get_name().to_string();
+    let shells = Shells::builtins();
+    let shell = shells
+        .completer(&args.shell)
+        .ok_or_else(|| GoaError::Other(format!("Unsupported shell: {}", args.shell)))?;
+
+    shell
+        .write_registration(COMPLETE_ENV, &name, &name, &name, &mut io::stdout())
+        .map_err(GoaError::Io)?;
+    Ok(())
+}
+
+pub fn handle_man_command(args: ManArgs, command: Command) -> Result<()> {
+    match args.output {
+        Some(dir) => {
+            utils::ensure_directory_exists(&dir)?;
+            clap_mangen::generate_to(command, &dir).map_err(GoaError::Io)?;
+            utils::log_success(&format!("Wrote man pages to {}", dir));
+        }
+        None => clap_mangen::Man::new(command).render(&mut io::stdout()).map_err(GoaError::Io)?,
+    }
+    Ok(())
+}
+
+pub fn api_route_candidates() -> Vec<CompletionCandidate> {
+    let api_dir = match load_project() {
+        Some((project_dir, config)) => project_dir.join(config.get_api_dir()),
+        None => return Vec::new(),
+    };
+
+    let mut routes: Vec<String> = WalkDir::new(&api_dir)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_name() == "route.go")
+        .filter_map(|entry| {
+            let dir = entry.path().parent()?.strip_prefix(&api_dir).ok()?;
+            Some(dir.to_string_lossy().replace('\\', "/"))
+        })
+        .filter(|route| !route.is_empty())
+        .collect();
+
+    routes.sort();
+    routes.into_iter().map(CompletionCandidate::new).collect()
+}
+
+pub fn page_route_candidates() -> Vec<CompletionCandidate> {
+    let app_dir = match load_project() {
+        Some((project_dir, config)) => project_dir.join(config.get_app_dir()),
+        None => return Vec::new(),
+    };
+
+    collect_page_routes(&app_dir)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|route| route.trim_start_matches('/').to_string())
+        .filter(|route| !route.is_empty())
+        .map(CompletionCandidate::new)
+        .collect()
+}
+
+pub fn component_candidates() -> Vec<CompletionCandidate> {
+    let components_dir = match load_project() {
+        Some((project_dir, config)) => project_dir.join(config.get_components_dir()),
+        None => return Vec::new(),
+    };
+
+    let mut names: Vec<String> = fs::read_dir(components_dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().map(|ext| ext == "html").unwrap_or(false))
+        .filter_map(|path| path.file_stem().map(|stem| stem.to_string_lossy().into_owned()))
+        .collect();
+
+    names.sort();
+    names.into_iter().map(CompletionCandidate::new).collect()
+}
+
+fn load_project() -> Option<(PathBuf, GoaConfig)> {
+    let config_path = find_config_file().ok()?;
+    let config = GoaConfig::load(&config_path).ok()?;
+    Some((config_path.parent()?.to_path_buf(), config))
+}
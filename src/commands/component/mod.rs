@@ -1,11 +1,19 @@
 use anyhow::Result;
 use clap::Subcommand;
-use std::path::PathBuf;
+use clap_complete::ArgValueCandidates;
+use serde_json::json;
+use std::fs;
+use std::path::{Path, PathBuf};
 
-use crate::config::GoaConfig;
-use crate::errors::{GoaError, GoaResult};
+use crate::commands::completions;
+use crate::config::{find_config_file, GoaConfig};
+use crate::errors::GoaError;
 use crate::templates;
+use crate::templates::component::Blueprint;
 use crate::utils;
+use crate::utils::logger;
+
+mod props;
 
 #[derive(Subcommand)]
 pub enum ComponentCommands {
@@ -13,23 +21,33 @@ pub enum ComponentCommands {
     New {
         
         name: Option<String>,
+        
+        #[clap(long, value_name = "PROPS", help = "Typed props, e.g. title:string,body:html,footer?:string (a trailing ? marks a prop as optional)")]
+        props: Option<String>,
+        
+        #[clap(long, value_enum, conflicts_with = "props", help = "Blueprint to start from [default: card]")]
+        template: Option<Blueprint>,
     },
     
     
     Delete {
-        
+        #[clap(add = ArgValueCandidates::new(completions::component_candidates))]
         name: Option<String>,
     },
+    
+    #[clap(about = "Report component invocations that are missing required props")]
+    Check,
 }
 
 pub fn handle_component_command(command: ComponentCommands) -> Result<()> {
     match command {
-        ComponentCommands::New { name } => create_component(name),
+        ComponentCommands::New { name, props, template } => create_component(name, props, template.unwrap_or(Blueprint::Card)),
         ComponentCommands::Delete { name } => delete_component(name),
+        ComponentCommands::Check => check_components(),
     }
 }
 
-fn create_component(name_option: Option<String>) -> Result<()> {
+fn create_component(name_option: Option<String>, props_option: Option<String>, blueprint: Blueprint) -> Result<()> {
     utils::log_step("Creating a new component");
     
     
@@ -40,16 +58,21 @@ fn create_component(name_option: Option<String>) -> Result<()> {
     
     
     if let Err(e) = utils::validate_project_name(&component_name) {
-        utils::log_error(&e);
         return Err(GoaError::ComponentGeneration(e).into());
     }
     
+    let props = props_option
+        .map(|spec| props::parse_props(&spec))
+        .transpose()
+        .map_err(GoaError::ComponentGeneration)?;
+    
     
     let config_path = find_config_file()?;
     let config = GoaConfig::load(&config_path)?;
+    let project_dir = config_path.parent().unwrap();
     
     
-    let components_dir = config.get_components_dir();
+    let components_dir = project_dir.join(config.get_components_dir());
     
     
     let component_file_path = components_dir.join(format!("{}.html", component_name));
@@ -60,8 +83,7 @@ fn create_component(name_option: Option<String>) -> Result<()> {
             &format!("Component '{}' already exists. Overwrite?", component_name),
             false,
         )? {
-            utils::log_info("Component creation cancelled");
-            return Ok(());
+            return Err(GoaError::Cancelled("Component creation cancelled".to_string()).into());
         }
     }
     
@@ -69,12 +91,25 @@ fn create_component(name_option: Option<String>) -> Result<()> {
     utils::ensure_directory_exists(&components_dir)?;
     
     
-    let component_content = templates::component::basic_component()
-        .replace("card", &component_name);
+    let component_content = match &props {
+        Some(props) => templates::component::props_component(
+            &component_name,
+            &props::contract_comment(props),
+            &props::component_docs(&component_name, props),
+            &props::component_body(props),
+        )?,
+        None => templates::component::blueprint_component(blueprint, &component_name)?,
+    };
     
     
     utils::write_file(&component_file_path, &component_content)?;
     
+    match &props {
+        Some(props) => write_props_helpers(project_dir, &component_name, props)?,
+        None if blueprint.uses_dict() => ensure_dict_helper(project_dir)?,
+        None => {}
+    }
+    
     utils::log_success(&format!("Component '{}' created successfully!", component_name));
     Ok(())
 }
@@ -91,55 +126,159 @@ fn delete_component(name_option: Option<String>) -> Result<()> {
     
     let config_path = find_config_file()?;
     let config = GoaConfig::load(&config_path)?;
+    let project_dir = config_path.parent().unwrap();
     
     
-    let components_dir = config.get_components_dir();
+    let components_dir = project_dir.join(config.get_components_dir());
     
     
     let component_file_path = components_dir.join(format!("{}.html", component_name));
+    let props_file_path = props_struct_path(project_dir, &component_name);
     
     
     if !component_file_path.exists() {
-        utils::log_error(&format!("Component '{}' does not exist", component_name));
         return Err(GoaError::ComponentGeneration(format!("Component '{}' does not exist", component_name)).into());
     }
     
     
-    if !utils::prompt_confirm(
-        &format!("Are you sure you want to delete the component '{}'?", component_name),
-        false,
-    )? {
-        utils::log_info("Component deletion cancelled");
-        return Ok(());
+    let prompt = if props_file_path.exists() {
+        format!(
+            "Are you sure you want to delete the component '{}' and its props struct {}?",
+            component_name,
+            props_file_path.strip_prefix(project_dir).unwrap_or(&props_file_path).display()
+        )
+    } else {
+        format!("Are you sure you want to delete the component '{}'?", component_name)
+    };
+    if !utils::prompt_confirm(&prompt, false)? {
+        return Err(GoaError::Cancelled("Component deletion cancelled".to_string()).into());
     }
     
     
     std::fs::remove_file(&component_file_path)
         .map_err(|e| GoaError::Io(e))?;
+    utils::log_file_deleted(&component_file_path, "file");
+    
+    if props_file_path.exists() {
+        std::fs::remove_file(&props_file_path)
+            .map_err(|e| GoaError::Io(e))?;
+        utils::log_file_deleted(&props_file_path, "file");
+    }
     
     utils::log_success(&format!("Component '{}' deleted successfully!", component_name));
     Ok(())
 }
 
-fn find_config_file() -> GoaResult<PathBuf> {
+fn props_struct_path(project_dir: &Path, component_name: &str) -> PathBuf {
+    project_dir.join(props::GO_PACKAGE).join(format!("{}_props.go", component_name.replace('-', "_")))
+}
+
+fn write_props_helpers(project_dir: &Path, component_name: &str, props: &[props::Prop]) -> Result<()> {
+    let struct_path = props_struct_path(project_dir, component_name);
+    utils::write_file(&struct_path, &props::go_props_struct(component_name, props::GO_PACKAGE, props))?;
+    
+    ensure_dict_helper(project_dir)
+}
+
+fn ensure_dict_helper(project_dir: &Path) -> Result<()> {
+    let dict_path = project_dir.join(props::GO_PACKAGE).join("dict.go");
+    if !dict_path.exists() {
+        utils::write_file(&dict_path, &templates::component::dict_helper(props::GO_PACKAGE))?;
+    }
+    
+    let registration = format!("{}.FuncMap()", props::GO_PACKAGE);
+    let registered = fs::read_to_string(project_dir.join("main.go"))
+        .map(|main| main.contains(&registration))
+        .unwrap_or(false);
+    if !registered {
+        utils::log_info(&format!(
+            "Register the dict helper in main.go: import \"{}/{}\" and call .Funcs({}) before parsing templates",
+            utils::go_module_name(project_dir),
+            props::GO_PACKAGE,
+            registration
+        ));
+    }
+    
+    Ok(())
+}
+
+fn check_components() -> Result<()> {
+    utils::log_step("Checking component props");
     
-    let current_dir = std::env::current_dir().map_err(|e| GoaError::Io(e))?;
-    let config_path = current_dir.join("config.json");
+    let config_path = find_config_file()?;
+    let config = GoaConfig::load(&config_path)?;
+    let project_dir = config_path.parent().unwrap();
     
-    if config_path.exists() {
-        return Ok(config_path);
+    let contracts = props::collect_contracts(&project_dir.join(config.get_components_dir()))?;
+    if contracts.is_empty() {
+        utils::log_info("No components declare props; create one with `goa component new <name> --props ...`");
+        return Ok(());
     }
     
+    let mut files = props::html_files(&project_dir.join(config.get_app_dir()));
+    for file in props::html_files(&project_dir.join(config.get_components_dir())) {
+        if !files.contains(&file) {
+            files.push(file);
+        }
+    }
     
-    let mut dir = current_dir;
-    while let Some(parent) = dir.parent() {
-        let parent_config = parent.join("config.json");
-        if parent_config.exists() {
-            return Ok(parent_config);
+    let problems = props::check_invocations(&files, &contracts)?;
+    let mut missing = 0;
+    let mut unchecked = 0;
+    for problem in &problems {
+        let location = format!(
+            "{}:{}",
+            problem.file.strip_prefix(project_dir).unwrap_or(&problem.file).display(),
+            problem.line
+        );
+        if problem.unchecked {
+            unchecked += 1;
+            utils::log_warning(&format!(
+                "{}: \"{}\" is unchecked because its props are not built with dict",
+                location, problem.component
+            ));
+        }
+        if !problem.missing.is_empty() {
+            missing += 1;
+            utils::log_error(&format!(
+                "{}: \"{}\" is missing required prop(s) {}",
+                location, problem.component, problem.missing.join(", ")
+            ));
+        }
+        if !problem.unknown.is_empty() {
+            utils::log_warning(&format!(
+                "{}: \"{}\" does not declare prop(s) {}",
+                location, problem.component, problem.unknown.join(", ")
+            ));
         }
-        dir = parent.to_path_buf();
     }
     
+    if logger::is_json() {
+        let problems: Vec<_> = problems
+            .iter()
+            .map(|problem| json!({
+                "file": problem.file.strip_prefix(project_dir).unwrap_or(&problem.file).display().to_string(),
+                "line": problem.line,
+                "component": problem.component,
+                "missing": problem.missing,
+                "unknown": problem.unknown,
+                "unchecked": problem.unchecked,
+            }))
+            .collect();
+        logger::set_result(json!({ "components": contracts.len(), "files": files.len(), "problems": problems }));
+    }
     
-    Err(GoaError::Configuration("Could not find config.json file. Are you inside a Go on Airplanes project?".to_string()))
-} 
\ No newline at end of file
+    if missing > 0 {
+        return Err(GoaError::Other(format!("{} component invocation(s) are missing required props", missing)).into());
+    }
+    
+    utils::log_success(&format!(
+        "Checked {} template file(s) against {} component(s) with props",
+        files.len(),
+        contracts.len()
+    ));
+    if unchecked > 0 {
+        utils::log_info(&format!("{} invocation(s) were unchecked; pass props with dict so they can be verified", unchecked));
+    }
+    Ok(())
+}
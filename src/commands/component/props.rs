@@ -0,0 +1,499 @@
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+use crate::errors::{GoaError, GoaResult};
+
+const CONTRACT_MARKER: &str = "goa:props";
+pub const GO_PACKAGE: &str = "props";
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum PropType {
+    String,
+    Html,
+    Url,
+    Int,
+    Float,
+    Bool,
+}
+
+impl PropType {
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "string" => Some(PropType::String),
+            "html" => Some(PropType::Html),
+            "url" => Some(PropType::Url),
+            "int" => Some(PropType::Int),
+            "float" => Some(PropType::Float),
+            "bool" => Some(PropType::Bool),
+            _ => None,
+        }
+    }
+
+    pub fn go_type(self) -> &'static str {
+        match self {
+            PropType::String => "string",
+            PropType::Html => "template.HTML",
+            PropType::Url => "template.URL",
+            PropType::Int => "int",
+            PropType::Float => "float64",
+            PropType::Bool => "bool",
+        }
+    }
+}
+
+impl fmt::Display for PropType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            PropType::String => "string",
+            PropType::Html => "html",
+            PropType::Url => "url",
+            PropType::Int => "int",
+            PropType::Float => "float",
+            PropType::Bool => "bool",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Clone)]
+pub struct Prop {
+    pub name: String,
+    pub kind: PropType,
+    pub optional: bool,
+}
+
+impl Prop {
+    pub fn field_name(&self) -> String {
+        pascal_case(&self.name)
+    }
+}
+
+impl fmt::Display for Prop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}:{}", self.name, if self.optional { "?" } else { "" }, self.kind)
+    }
+}
+
+pub struct MissingProps {
+    pub file: PathBuf,
+    pub line: usize,
+    pub component: String,
+    pub missing: Vec<String>,
+    pub unknown: Vec<String>,
+    pub unchecked: bool,
+}
+
+pub fn parse_props(spec: &str) -> Result<Vec<Prop>, String> {
+    let name_regex = Regex::new(r"^[a-z][a-zA-Z0-9_]*$").unwrap();
+    let mut props: Vec<Prop> = Vec::new();
+
+    for entry in spec.split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+        let (name, kind) = entry.split_once(':').unwrap_or((entry, "string"));
+        let (name, optional) = match name.trim().strip_suffix('?') {
+            Some(name) => (name, true),
+            None => (name.trim(), false),
+        };
+
+        if !name_regex.is_match(name) {
+            return Err(format!(
+                "Prop name '{}' must start with a lowercase letter and contain only letters, numbers and underscores",
+                name
+            ));
+        }
+        let kind = PropType::parse(kind.trim()).ok_or_else(|| format!(
+            "Unknown type '{}' for prop '{}' (expected string, html, url, int, float or bool)",
+            kind.trim(),
+            name
+        ))?;
+        if props.iter().any(|prop| prop.field_name() == pascal_case(name)) {
+            return Err(format!("Prop '{}' is defined more than once", name));
+        }
+
+        props.push(Prop { name: name.to_string(), kind, optional });
+    }
+
+    if props.is_empty() {
+        return Err("--props needs at least one prop, e.g. title:string,body:html,footer?:string".to_string());
+    }
+    Ok(props)
+}
+
+pub fn contract_comment(props: &[Prop]) -> String {
+    let spec = props.iter().map(Prop::to_string).collect::<Vec<_>>().join(",");
+    format!("{{{{/* {} {} */}}}}", CONTRACT_MARKER, spec)
+}
+
+pub fn parse_contract(template: &str) -> Option<Vec<Prop>> {
+    let marker = Regex::new(&format!(r"\{{\{{-?\s*/\*\s*{}\s+([^*]*?)\s*\*/\s*-?\}}\}}", CONTRACT_MARKER)).unwrap();
+    let spec = marker.captures(template)?.get(1)?.as_str().to_string();
+    parse_props(&spec).ok()
+}
+
+pub fn component_docs(component_name: &str, props: &[Prop]) -> String {
+    let width = props.iter().map(|prop| prop.field_name().len() + 1).max().unwrap_or(0);
+    let type_width = props.iter().map(|prop| prop.kind.go_type().len()).max().unwrap_or(0);
+
+    let mut lines = vec!["  Props:".to_string()];
+    for prop in props {
+        lines.push(format!(
+            "    {:<width$}  {:<type_width$}  {}",
+            format!(".{}", prop.field_name()),
+            prop.kind.go_type(),
+            if prop.optional { "optional" } else { "required" },
+            width = width,
+            type_width = type_width
+        ));
+    }
+
+    let example = props
+        .iter()
+        .filter(|prop| !prop.optional)
+        .map(|prop| format!(" \"{}\" {}", prop.field_name(), example_value(prop.kind)))
+        .collect::<String>();
+    lines.push(String::new());
+    lines.push(format!("  Usage: {{{{ template \"{}\" dict{} }}}}", component_name, example));
+    lines.join("\n")
+}
+
+pub fn component_body(props: &[Prop]) -> String {
+    props
+        .iter()
+        .map(|prop| {
+            let class = prop.name.replace('_', "-");
+            if prop.optional {
+                format!("        {{{{ with .{} }}}}<div class=\"{}\">{{{{ . }}}}</div>{{{{ end }}}}", prop.field_name(), class)
+            } else {
+                format!("        <div class=\"{}\">{{{{ .{} }}}}</div>", class, prop.field_name())
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn go_props_struct(component_name: &str, package: &str, props: &[Prop]) -> String {
+    let pascal = pascal_case(component_name);
+    let type_name = format!("{}Props", pascal);
+    let width = props.iter().map(|prop| prop.field_name().len()).max().unwrap_or(0);
+    let type_width = props.iter().map(|prop| prop.kind.go_type().len()).max().unwrap_or(0);
+    let needs_template = props.iter().any(|prop| matches!(prop.kind, PropType::Html | PropType::Url));
+
+    let fields = props
+        .iter()
+        .map(|prop| {
+            let requirement = if prop.optional { "optional" } else { "required" };
+            format!(
+                "\t{:<width$} {:<type_width$} // {}, {}",
+                prop.field_name(),
+                prop.kind.go_type(),
+                prop.name,
+                requirement,
+                width = width,
+                type_width = type_width
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let imports = if needs_template { "import \"html/template\"\n\n" } else { "" };
+
+    format!(
+        r#"// Code generated by goa component new; DO NOT EDIT.
+
+package {package}
+
+{imports}// {type_name} holds the props of the "{component_name}" component.
+// Pass a {type_name} value to the component, e.g. {{{{ template "{component_name}" .{pascal} }}}},
+// or build the props inline with {{{{ template "{component_name}" dict "Key" value ... }}}}.
+type {type_name} struct {{
+{fields}
+}}
+"#
+    )
+}
+
+pub fn check_invocations(
+    template_files: &[PathBuf],
+    contracts: &BTreeMap<String, Vec<Prop>>,
+) -> GoaResult<Vec<MissingProps>> {
+    let invocation = Regex::new(r#"\{\{-?\s*template\s+"([^"]+)"\s*(.*?)\s*-?\}\}"#).unwrap();
+    let mut problems = Vec::new();
+
+    for file in template_files {
+        let content = fs::read_to_string(file).map_err(GoaError::Io)?;
+
+        for captures in invocation.captures_iter(&content) {
+            let component = &captures[1];
+            let props = match contracts.get(component) {
+                Some(props) => props,
+                None => continue,
+            };
+
+            let offset = captures.get(0).map(|m| m.start()).unwrap_or(0);
+            let line = content[..offset].matches('\n').count() + 1;
+
+            let keys = match passed_keys(&captures[2]) {
+                Some(keys) => keys,
+                None => {
+                    problems.push(MissingProps {
+                        file: file.clone(),
+                        line,
+                        component: component.to_string(),
+                        missing: Vec::new(),
+                        unknown: Vec::new(),
+                        unchecked: true,
+                    });
+                    continue;
+                }
+            };
+
+            let missing: Vec<String> = props
+                .iter()
+                .filter(|prop| !prop.optional && !keys.contains(&prop.field_name()))
+                .map(Prop::field_name)
+                .collect();
+            let unknown: Vec<String> = keys
+                .iter()
+                .filter(|key| !props.iter().any(|prop| &prop.field_name() == *key))
+                .cloned()
+                .collect();
+
+            if !missing.is_empty() || !unknown.is_empty() {
+                problems.push(MissingProps {
+                    file: file.clone(),
+                    line,
+                    component: component.to_string(),
+                    missing,
+                    unknown,
+                    unchecked: false,
+                });
+            }
+        }
+    }
+
+    Ok(problems)
+}
+
+pub fn collect_contracts(components_dir: &Path) -> GoaResult<BTreeMap<String, Vec<Prop>>> {
+    let define = Regex::new(r#"\{\{-?\s*define\s+"([^"]+)"\s*-?\}\}"#).unwrap();
+    let mut contracts = BTreeMap::new();
+
+    for path in html_files(components_dir) {
+        let content = fs::read_to_string(&path).map_err(GoaError::Io)?;
+        let props = match parse_contract(&content) {
+            Some(props) => props,
+            None => continue,
+        };
+
+        for captures in define.captures_iter(&content) {
+            contracts.insert(captures[1].to_string(), props.clone());
+        }
+    }
+
+    Ok(contracts)
+}
+
+pub fn html_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = WalkDir::new(dir)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.into_path())
+        .filter(|path| path.is_file() && path.extension().map(|ext| ext == "html").unwrap_or(false))
+        .collect();
+    files.sort();
+    files
+}
+
+fn passed_keys(pipeline: &str) -> Option<Vec<String>> {
+    let pipeline = pipeline.trim();
+    if pipeline.is_empty() {
+        return Some(Vec::new());
+    }
+
+    let pipeline = pipeline
+        .strip_prefix('(')
+        .and_then(|inner| inner.strip_suffix(')'))
+        .unwrap_or(pipeline);
+    let tokens = tokenize(pipeline);
+    if tokens.first().map(String::as_str) != Some("dict") {
+        return None;
+    }
+
+    Some(
+        tokens[1..]
+            .iter()
+            .step_by(2)
+            .filter_map(|token| token.strip_prefix('"').and_then(|key| key.strip_suffix('"')))
+            .map(str::to_string)
+            .collect(),
+    )
+}
+
+fn tokenize(pipeline: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for c in pipeline.chars() {
+        if in_string {
+            current.push(c);
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match c {
+            '"' => {
+                in_string = true;
+                current.push(c);
+            }
+            '(' => {
+                depth += 1;
+                current.push(c);
+            }
+            ')' => {
+                depth -= 1;
+                current.push(c);
+            }
+            c if c.is_whitespace() && depth == 0 => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    tokens
+}
+
+fn example_value(kind: PropType) -> &'static str {
+    match kind {
+        PropType::String | PropType::Html => "\"...\"",
+        PropType::Url => "\"/path\"",
+        PropType::Int => "1",
+        PropType::Float => "1.5",
+        PropType::Bool => "true",
+    }
+}
+
+fn pascal_case(name: &str) -> String {
+    name.split(['_', '-'])
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error(spec: &str) -> String {
+        parse_props(spec).err().unwrap()
+    }
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn parses_types_and_optional_props() {
+        let props = parse_props("title:string, body:html,footer?:url,count").unwrap();
+
+        let parsed: Vec<String> = props.iter().map(Prop::to_string).collect();
+        assert_eq!(parsed, strings(&["title:string", "body:html", "footer?:url", "count:string"]));
+        assert!(!props[0].optional);
+        assert!(props[2].optional);
+        assert_eq!(props[2].field_name(), "Footer");
+    }
+
+    #[test]
+    fn rejects_bad_types_and_names() {
+        assert!(parse_error("title:text").contains("Unknown type 'text'"));
+        assert!(parse_error("Title:string").contains("must start with a lowercase letter"));
+        assert!(parse_error("").contains("at least one prop"));
+    }
+
+    #[test]
+    fn rejects_duplicate_props() {
+        assert!(parse_error("title,title?:html").contains("defined more than once"));
+        assert!(parse_error("user_name,userName").contains("'userName' is defined more than once"));
+    }
+
+    #[test]
+    fn contract_comment_round_trips() {
+        let props = parse_props("id:int,label?:string").unwrap();
+        let comment = contract_comment(&props);
+
+        assert_eq!(comment, "{{/* goa:props id:int,label?:string */}}");
+        let parsed: Vec<String> = parse_contract(&comment).unwrap().iter().map(Prop::to_string).collect();
+        assert_eq!(parsed, strings(&["id:int", "label?:string"]));
+    }
+
+    #[test]
+    fn tokenize_keeps_strings_and_parentheses_together() {
+        assert_eq!(
+            tokenize(r#"dict "Title" "Hello \"there\" world" "Body" (printf "%s x" .Name)"#),
+            strings(&["dict", r#""Title""#, r#""Hello \"there\" world""#, r#""Body""#, r#"(printf "%s x" .Name)"#])
+        );
+    }
+
+    #[test]
+    fn passed_keys_reads_dict_keys() {
+        assert_eq!(passed_keys(r#"dict "Title" .Title "Body" (html .Body)"#), Some(strings(&["Title", "Body"])));
+        assert_eq!(passed_keys(r#"(dict "Title" "x")"#), Some(strings(&["Title"])));
+        assert_eq!(passed_keys(""), Some(Vec::new()));
+        assert_eq!(passed_keys("."), None);
+        assert_eq!(passed_keys(".Card"), None);
+    }
+
+    #[test]
+    fn check_invocations_reports_missing_unknown_and_unchecked() {
+        let dir = tempfile::tempdir().unwrap();
+        let page = dir.path().join("page.html");
+        fs::write(
+            &page,
+            concat!(
+                "{{ template \"card\" dict \"Title\" \"Hi\" \"Body\" .Body }}\n",
+                "{{ template \"card\" dict \"Title\" \"Hi\" \"Colour\" \"red\" }}\n",
+                "{{- template \"card\" .Card -}}\n",
+                "{{ template \"other\" . }}\n",
+            ),
+        )
+        .unwrap();
+
+        let mut contracts = BTreeMap::new();
+        contracts.insert("card".to_string(), parse_props("title,body:html,footer?:html").unwrap());
+
+        let problems = check_invocations(std::slice::from_ref(&page), &contracts).unwrap();
+
+        assert_eq!(problems.len(), 2);
+        assert_eq!(problems[0].file, page);
+        assert_eq!(problems[0].line, 2);
+        assert_eq!(problems[0].missing, strings(&["Body"]));
+        assert_eq!(problems[0].unknown, strings(&["Colour"]));
+        assert!(!problems[0].unchecked);
+        assert_eq!(problems[1].line, 3);
+        assert!(problems[1].unchecked);
+        assert!(problems[1].missing.is_empty());
+    }
+}
@@ -0,0 +1,337 @@
+use anyhow::Result;
+use clap::Subcommand;
+use colored::Colorize;
+
+use serde_json::{json, Value};
+use similar::TextDiff;
+use std::fs;
+use std::path::Path;
+
+use crate::config::{
+    find_config_file, migrate_config, ConfigDocument, MigrationPlan, ConfigPath, GoaConfig, LayeredConfig, CURRENT_CONFIG_VERSION,
+};
+use crate::commands::env::warn_about_config_secrets;
+use crate::commands::project::{relocate_directory, MOVABLE_DIRECTORIES};
+use crate::errors::GoaError;
+use crate::utils;
+use crate::utils::logger;
+
+#[derive(Subcommand)]
+pub enum ConfigCommands {
+    #[clap(about = "Print the JSON Schema for config.json")]
+    Schema {
+        #[clap(long = "out-file", short = 'o', value_name = "FILE", help = "Write the schema to a file instead of stdout")]
+        output: Option<String>,
+    },
+
+    #[clap(about = "Validate config.json and report problems")]
+    Validate,
+
+    #[clap(about = "Print a config value, e.g. `goa config get server.port`")]
+    Get {
+        #[clap(help = "Dotted path such as cdn.useCDN or server.allowedOrigins[0]")]
+        path: String,
+
+        #[clap(long, help = "Read the value with the config.<env>.json overlay applied")]
+        env: Option<String>,
+    },
+
+    #[clap(about = "Print the effective config and where each value comes from")]
+    Show {
+        #[clap(long, help = "Apply the config.<env>.json overlay, e.g. production")]
+        env: Option<String>,
+    },
+
+    #[clap(about = "Set a config value, e.g. `goa config set server.port 8080`")]
+    Set {
+        #[clap(help = "Dotted path such as server.port or server.allowedOrigins[1]")]
+        path: String,
+
+        #[clap(help = "New value; lists and objects are given as JSON")]
+        value: String,
+
+        #[clap(long, help = "Move the existing files when changing directories.appDir or directories.componentDir")]
+        move_files: bool,
+    },
+
+    #[clap(about = "Upgrade config.json to the current config version")]
+    Migrate {
+        #[clap(long, help = "Show the changes without writing config.json")]
+        dry_run: bool,
+    },
+
+    #[clap(about = "Remove a config value so the default applies")]
+    Unset {
+        #[clap(help = "Dotted path such as server.allowedOrigins[2]")]
+        path: String,
+    },
+}
+
+pub fn handle_config_command(command: ConfigCommands) -> Result<()> {
+    match command {
+        ConfigCommands::Schema { output } => print_schema(output),
+        ConfigCommands::Validate => validate_config(),
+        ConfigCommands::Get { path, env } => get_value(&path, env.as_deref()),
+        ConfigCommands::Show { env } => show_config(env.as_deref()),
+        ConfigCommands::Set { path, value, move_files } => set_value(&path, &value, move_files),
+        ConfigCommands::Unset { path } => unset_value(&path),
+        ConfigCommands::Migrate { dry_run } => migrate(dry_run),
+    }
+}
+
+fn print_schema(output: Option<String>) -> Result<()> {
+    let schema = serde_json::to_string_pretty(&GoaConfig::schema())
+        .map_err(GoaError::Json)?;
+
+    match output {
+        Some(path) => utils::write_file(&path, &format!("{}\n", schema))?,
+        None if logger::is_json() => logger::set_result(GoaConfig::schema()),
+        None => println!("{}", schema),
+    }
+
+    Ok(())
+}
+
+fn validate_config() -> Result<()> {
+    utils::log_step("Validating config.json");
+
+    let config_path = find_config_file()?;
+    let config = GoaConfig::load(&config_path)?;
+    config.validate()?;
+
+    let unknown: Vec<&String> = config.extra.keys().filter(|key| !key.starts_with('$')).collect();
+    if !unknown.is_empty() {
+        utils::log_warning(&format!(
+            "Unknown top-level key(s) kept as-is: {}",
+            unknown.iter().map(|k| k.as_str()).collect::<Vec<_>>().join(", ")
+        ));
+    }
+
+    let version = config.config_version.unwrap_or(0);
+    if version < CURRENT_CONFIG_VERSION {
+        utils::log_warning(&format!(
+            "config.json uses config version {} (current is {}); run `goa config migrate` to upgrade it",
+            version, CURRENT_CONFIG_VERSION
+        ));
+    }
+
+    warn_about_config_secrets(&config_path);
+
+    utils::log_success(&format!("{} is valid", config_path.display()));
+    Ok(())
+}
+
+fn get_value(path: &str, env: Option<&str>) -> Result<()> {
+    let path = ConfigPath::parse(path)?;
+    let effective = LayeredConfig::load(&find_config_file()?, env)?.value()?;
+
+    let value = path.lookup(&effective)
+        .ok_or_else(|| GoaError::Configuration(format!("`{}` is not set in config.json", path)))?;
+
+    if logger::is_json() {
+        logger::set_result(json!({ "path": path.to_string(), "value": value }));
+    } else {
+        println!("{}", format_value(value)?);
+    }
+    Ok(())
+}
+
+fn show_config(env: Option<&str>) -> Result<()> {
+    let layered = LayeredConfig::load(&find_config_file()?, env)?;
+
+    match &layered.env {
+        Some(env) => utils::log_step(&format!("Effective configuration for {}", env)),
+        None => utils::log_step("Effective configuration"),
+    }
+
+    let entries = layered.sources()?;
+    if logger::is_json() {
+        let entries: Vec<_> = entries
+            .into_iter()
+            .map(|(path, value, source)| json!({ "path": path, "value": value, "source": source }))
+            .collect();
+        logger::set_result(json!({ "env": layered.env, "entries": entries }));
+        return Ok(());
+    }
+
+    let width = entries.iter().map(|(path, _, _)| path.len()).max().unwrap_or(0);
+
+    for (path, value, source) in entries {
+        let value = serde_json::to_string(&value).map_err(GoaError::Json)?;
+        println!("{:<width$}  {}  {}", path, value, format!("({})", source).dimmed(), width = width);
+    }
+
+    Ok(())
+}
+
+fn set_value(path: &str, raw: &str, move_files: bool) -> Result<()> {
+    let path = ConfigPath::parse(path)?;
+    let config_path = find_config_file()?;
+    let mut document = ConfigDocument::load(&config_path)?;
+
+    let key = path.to_string();
+    if MOVABLE_DIRECTORIES.contains(&key.as_str()) {
+        let project_dir = config_path.parent().unwrap();
+        let config = document.config()?;
+        let current = if key == "directories.appDir" { &config.directories.app_dir } else { &config.directories.component_dir };
+
+        let should_move = current != raw
+            && project_dir.join(current).exists()
+            && (move_files || (console::user_attended() && utils::prompt_confirm(
+                format!("Move the existing files from {} to {}", current, raw),
+                true,
+            )?));
+
+        if should_move {
+            utils::validate_directory(project_dir, raw).map_err(GoaError::InvalidPath)?;
+            relocate_directory(project_dir, &config, &key, raw, move_files, |changes| {
+                for (setting, value) in changes {
+                    document.set(&ConfigPath::parse(setting)?, value)?;
+                }
+                document.save()?;
+                Ok(())
+            })?;
+            utils::log_success(&format!("Set {} = {:?}", path, raw));
+            return Ok(());
+        }
+
+        if current != raw && project_dir.join(current).exists() {
+            utils::log_warning(&format!(
+                "Existing files in {} were not moved; pass --move-files to relocate them",
+                current
+            ));
+        }
+    }
+
+    let value = document.set(&path, raw)?;
+    document.save()?;
+
+    utils::log_success(&format!("Set {} = {}", path, serde_json::to_string(&value).map_err(GoaError::Json)?));
+    Ok(())
+}
+
+fn unset_value(path: &str) -> Result<()> {
+    let path = ConfigPath::parse(path)?;
+    let mut document = ConfigDocument::load(find_config_file()?)?;
+
+    let removed = document.unset(&path)?;
+    document.save()?;
+
+    utils::log_success(&format!("Removed {} (was {})", path, serde_json::to_string(&removed).map_err(GoaError::Json)?));
+    Ok(())
+}
+
+fn migrate(dry_run: bool) -> Result<()> {
+    migrate_file(&find_config_file()?, dry_run)
+}
+
+fn migrate_file(config_path: &Path, dry_run: bool) -> Result<()> {
+    let source = fs::read_to_string(config_path)
+        .map_err(GoaError::Io)?;
+
+    let plan = migrate_config(&source, config_path)?;
+    if plan.steps.is_empty() {
+        utils::log_success(&format!("config.json is already at config version {}", plan.from));
+        return Ok(());
+    }
+
+    utils::log_step(&format!("Migrating config.json from version {} to {}", plan.from, plan.to));
+    let diff = TextDiff::from_lines(&plan.original, &plan.migrated);
+    let unified = diff.unified_diff().context_radius(3).header("config.json", "config.json (migrated)").to_string();
+
+    if logger::is_json() {
+        let steps: Vec<_> = plan.steps
+            .iter()
+            .map(|step| json!({ "version": step.version, "description": step.description, "changes": step.changes }))
+            .collect();
+        logger::set_result(json!({ "from": plan.from, "to": plan.to, "dryRun": dry_run, "steps": steps, "diff": unified }));
+    } else {
+        print_migration(&plan, &unified);
+    }
+
+    if dry_run {
+        utils::log_info("Dry run: config.json was not changed");
+        return Ok(());
+    }
+
+    let backup_path = config_path.with_file_name(format!("config.json.v{}.bak", plan.from));
+    fs::write(&backup_path, &plan.original)
+        .map_err(GoaError::Io)?;
+    fs::write(config_path, &plan.migrated)
+        .map_err(GoaError::Io)?;
+
+    utils::log_success(&format!(
+        "config.json migrated to version {} (original saved as {})",
+        plan.to,
+        backup_path.file_name().unwrap_or_default().to_string_lossy()
+    ));
+    Ok(())
+}
+
+fn format_value(value: &Value) -> Result<String> {
+    Ok(match value {
+        Value::String(s) => s.clone(),
+        Value::Array(_) | Value::Object(_) => serde_json::to_string_pretty(value).map_err(GoaError::Json)?,
+        other => other.to_string(),
+    })
+}
+
+fn print_migration(plan: &MigrationPlan, unified: &str) {
+    for step in &plan.steps {
+        utils::log_info(&format!("v{}: {}", step.version, step.description));
+        if step.changes.is_empty() {
+            println!("    - nothing to change");
+        }
+        for change in &step.changes {
+            println!("    - {}", change);
+        }
+    }
+
+    println!();
+    for line in unified.lines() {
+        if line.starts_with("+++") || line.starts_with("---") {
+            println!("{}", line.bold());
+        } else if line.starts_with('+') {
+            println!("{}", line.green());
+        } else if line.starts_with('-') {
+            println!("{}", line.red());
+        } else if line.starts_with("@@") {
+            println!("{}", line.cyan());
+        } else {
+            println!("{}", line);
+        }
+    }
+    println!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEGACY_CONFIG: &str = "{\n  \"name\": \"shop\",\n  \"server\": {\n    \"port\": \"8080\"\n  }\n}\n";
+
+    #[test]
+    fn migrate_dry_run_leaves_config_unchanged() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_path = dir.path().join("config.json");
+        fs::write(&config_path, LEGACY_CONFIG).unwrap();
+
+        migrate_file(&config_path, true).unwrap();
+
+        assert_eq!(fs::read_to_string(&config_path).unwrap(), LEGACY_CONFIG);
+        assert!(!dir.path().join("config.json.v0.bak").exists());
+    }
+
+    #[test]
+    fn migrate_writes_config_and_backup() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_path = dir.path().join("config.json");
+        fs::write(&config_path, LEGACY_CONFIG).unwrap();
+
+        migrate_file(&config_path, false).unwrap();
+
+        let migrated: Value = serde_json::from_str(&fs::read_to_string(&config_path).unwrap()).unwrap();
+        assert_eq!(migrated["configVersion"], CURRENT_CONFIG_VERSION);
+        assert_eq!(fs::read_to_string(dir.path().join("config.json.v0.bak")).unwrap(), LEGACY_CONFIG);
+    }
+}
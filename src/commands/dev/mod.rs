@@ -0,0 +1,123 @@
+use anyhow::Result;
+use clap::Args;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::commands::assets;
+use crate::commands::env::{warn_about_config_secrets, EnvFile};
+use crate::config::{find_config_file, overlay_path, ConfigOverride, LayeredConfig, DEV_ENV};
+use crate::errors::GoaError;
+use crate::utils;
+
+const WATCH_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Args)]
+pub struct DevArgs {
+    #[clap(long, help = "Compile Tailwind CSS and rebuild it when templates change")]
+    css: bool,
+
+    #[clap(long, help = "Do not compile or watch Tailwind CSS")]
+    no_css: bool,
+
+    #[clap(long, help = "Environment whose config.<env>.json overlay is applied (defaults to dev when config.dev.json exists)")]
+    env: Option<String>,
+}
+
+pub fn handle_dev_command(args: DevArgs) -> Result<()> {
+    utils::log_step("Starting development server");
+
+    let config_path = find_config_file()?;
+    let project_dir = config_path.parent().unwrap().to_path_buf();
+
+    let env = args.env.or_else(|| overlay_path(&config_path, DEV_ENV).exists().then(|| DEV_ENV.to_string()));
+    let layered = LayeredConfig::load(&config_path, env.as_deref())?;
+    let config = layered.config.clone();
+
+    let _config_override = match &env {
+        Some(env) if layered.has_overrides() => {
+            let guard = ConfigOverride::apply(&config_path, &config)?;
+            utils::log_info(&format!("Using {} config; config.json is restored when the server stops", env));
+            Some(guard)
+        }
+        _ => None,
+    };
+
+    warn_about_config_secrets(&config_path);
+
+    let (exported, env_vars): (Vec<_>, Vec<_>) = EnvFile::load(&project_dir)?
+        .vars()
+        .into_iter()
+        .partition(|(key, _)| std::env::var_os(key).is_some());
+    if !exported.is_empty() {
+        utils::log_debug(&format!(
+            "Kept exported value(s) of {} over .env",
+            exported.iter().map(|(key, _)| key.as_str()).collect::<Vec<_>>().join(", ")
+        ));
+    }
+    if !env_vars.is_empty() {
+        utils::log_info(&format!("Loaded {} variable(s) from .env", env_vars.len()));
+    }
+
+    let interrupted = Arc::new(AtomicBool::new(false));
+    let handler_flag = interrupted.clone();
+    ctrlc::set_handler(move || handler_flag.store(true, Ordering::SeqCst))
+        .map_err(|e| GoaError::Other(format!("Failed to install interrupt handler: {}", e)))?;
+
+    let watch_css = !args.no_css && (args.css || assets::css_output_path(&project_dir, &config).exists());
+    let mut snapshot = None;
+
+    if watch_css {
+        assets::compile_css(&project_dir, &config)?;
+        assets::hint_unlinked_css(&project_dir, &config);
+        snapshot = Some(template_snapshot(&assets::collect_template_files(&project_dir, &config)));
+        utils::log_info("Watching templates for Tailwind class changes");
+    }
+
+    let mut server = Command::new("go");
+    server.args(["run", "main.go"]).envs(env_vars).current_dir(&project_dir);
+    utils::log_command(&server);
+    let mut child = server
+        .spawn()
+        .map_err(|e| GoaError::Other(format!("Failed to start development server: {}", e)))?;
+
+    loop {
+        if interrupted.load(Ordering::SeqCst) {
+            child.kill().ok();
+            child.wait().ok();
+            utils::log_info("Development server stopped");
+            return Ok(());
+        }
+
+        if let Some(status) = child.try_wait().map_err(GoaError::Io)? {
+            if status.success() {
+                return Ok(());
+            }
+            return Err(GoaError::Other(format!("Development server exited with {}", status)).into());
+        }
+
+        if let Some(previous) = &snapshot {
+            let current = template_snapshot(&assets::collect_template_files(&project_dir, &config));
+            if &current != previous {
+                utils::log_step("Templates changed, rebuilding CSS...");
+                if let Err(e) = assets::compile_css(&project_dir, &config) {
+                    utils::log_warning(&format!("CSS rebuild failed: {}", e));
+                }
+                snapshot = Some(current);
+            }
+        }
+
+        thread::sleep(WATCH_INTERVAL);
+    }
+}
+
+fn template_snapshot(files: &[PathBuf]) -> Vec<(PathBuf, Option<SystemTime>)> {
+    files
+        .iter()
+        .map(|file| (file.clone(), fs::metadata(file).and_then(|m| m.modified()).ok()))
+        .collect()
+}
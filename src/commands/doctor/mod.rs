@@ -0,0 +1,411 @@
+use anyhow::Result;
+use clap::Args;
+use colored::Colorize;
+use serde::Serialize;
+use std::fs;
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use walkdir::WalkDir;
+
+use crate::config::{find_config_file, GoaConfig, CURRENT_CONFIG_VERSION};
+use crate::errors::GoaError;
+use crate::requirements::{go_version, parse_version, required_go_version, GO_DOWNLOAD_URL};
+use crate::utils;
+use crate::utils::logger;
+use crate::version::{self, UpdateStatus};
+
+#[derive(Args)]
+pub struct DoctorArgs {
+    #[clap(long, hide = true, help = "Alias for --output json")]
+    pub json: bool,
+}
+
+#[derive(Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum Status {
+    Pass,
+    Warn,
+    Fail,
+}
+
+#[derive(Serialize)]
+struct Check {
+    name: &'static str,
+    status: Status,
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hint: Option<String>,
+}
+
+#[derive(Serialize)]
+struct Summary {
+    pass: usize,
+    warn: usize,
+    fail: usize,
+}
+
+#[derive(Serialize)]
+struct Report {
+    checks: Vec<Check>,
+    summary: Summary,
+}
+
+impl Check {
+    fn new(name: &'static str, status: Status, message: impl Into<String>) -> Self {
+        Self { name, status, message: message.into(), hint: None }
+    }
+
+    fn hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+}
+
+struct Project {
+    dir: PathBuf,
+    config: Option<GoaConfig>,
+}
+
+pub fn handle_doctor_command() -> Result<()> {
+    let mut checks = Vec::new();
+
+    checks.push(check_git());
+
+    let project_dir = find_config_file().ok().and_then(|path| path.parent().map(Path::to_path_buf));
+    let go_version = go_version();
+    checks.push(check_go_version(go_version.as_deref(), project_dir.as_deref()));
+    if go_version.is_some() {
+        checks.push(check_go_paths());
+    }
+
+    match project_dir {
+        Some(dir) => {
+            let (check, config) = check_config(&dir);
+            checks.push(check);
+
+            let project = Project { dir, config };
+            checks.push(check_main_file(&project));
+            checks.push(check_layout(&project));
+            checks.push(check_api_imports(&project));
+            checks.push(check_port(&project));
+        }
+        None => checks.push(
+            Check::new("config", Status::Warn, "No config.json found; project checks were skipped")
+                .hint("Run `goa doctor` inside a Go on Airplanes project or create one with `goa project new`"),
+        ),
+    }
+
+    checks.push(check_updates());
+
+    let count = |status| checks.iter().filter(|check| check.status == status).count();
+    let summary = Summary {
+        pass: count(Status::Pass),
+        warn: count(Status::Warn),
+        fail: count(Status::Fail),
+    };
+    let failed = summary.fail;
+    let report = Report { checks, summary };
+
+    if logger::is_json() {
+        logger::set_result(serde_json::to_value(&report).map_err(GoaError::Json)?);
+    } else {
+        print_report(&report);
+    }
+
+    if failed > 0 {
+        return Err(GoaError::Other(format!("{} doctor check(s) failed", failed)).into());
+    }
+    Ok(())
+}
+
+fn print_report(report: &Report) {
+    utils::log_step("Checking your environment and project");
+    println!();
+
+    let width = report.checks.iter().map(|check| check.name.len()).max().unwrap_or(0);
+    for check in &report.checks {
+        let label = match check.status {
+            Status::Pass => "PASS".green().bold(),
+            Status::Warn => "WARN".yellow().bold(),
+            Status::Fail => "FAIL".red().bold(),
+        };
+        println!("  {}  {:<width$}  {}", label, check.name, check.message, width = width);
+        if let Some(hint) = &check.hint {
+            println!("        {:<width$}  {}", "", hint.dimmed(), width = width);
+        }
+    }
+
+    println!();
+    println!(
+        "{} passed, {} warning(s), {} failed",
+        report.summary.pass.to_string().green(),
+        report.summary.warn.to_string().yellow(),
+        report.summary.fail.to_string().red()
+    );
+}
+
+fn check_git() -> Check {
+    match Command::new("git").arg("--version").output() {
+        Ok(output) if output.status.success() => {
+            Check::new("git", Status::Pass, String::from_utf8_lossy(&output.stdout).trim().to_string())
+        }
+        _ => Check::new("git", Status::Fail, "Git is not installed")
+            .hint("Install it from https://git-scm.com/downloads"),
+    }
+}
+
+fn check_go_version(installed: Option<&str>, project_dir: Option<&Path>) -> Check {
+    let installed = match installed {
+        Some(installed) => installed,
+        None => {
+            return Check::new("go", Status::Fail, "Go is not installed")
+                .hint(format!("Install it from {}", GO_DOWNLOAD_URL));
+        }
+    };
+
+    match project_dir.and_then(required_go_version) {
+        Some(required) if parse_version(installed) < parse_version(&required) => Check::new(
+            "go",
+            Status::Fail,
+            format!("Go {} is older than the {} required by go.mod", installed, required),
+        )
+        .hint(format!("Upgrade Go from {}", GO_DOWNLOAD_URL)),
+        Some(required) => Check::new("go", Status::Pass, format!("Go {} (go.mod requires {})", installed, required)),
+        None => Check::new("go", Status::Pass, format!("Go {}", installed)),
+    }
+}
+
+fn check_go_paths() -> Check {
+    let output = match Command::new("go").args(["env", "GOPATH", "GOMODCACHE"]).output() {
+        Ok(output) if output.status.success() => output,
+        _ => return Check::new("go paths", Status::Warn, "Could not read `go env GOPATH GOMODCACHE`"),
+    };
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut values = stdout.lines().map(str::trim);
+    let gopath = values.next().unwrap_or("").to_string();
+    let modcache = values.next().filter(|v| !v.is_empty()).map(str::to_string)
+        .unwrap_or_else(|| Path::new(&gopath).join("pkg").join("mod").display().to_string());
+
+    if gopath.is_empty() {
+        return Check::new("go paths", Status::Warn, "GOPATH is not set")
+            .hint("Set GOPATH or let Go use its default by unsetting it");
+    }
+
+    for (name, dir) in [("GOPATH", &gopath), ("GOMODCACHE", &modcache)] {
+        let path = Path::new(dir);
+        match fs::metadata(path) {
+            Ok(metadata) if !metadata.is_dir() => {
+                return Check::new("go paths", Status::Fail, format!("{} ({}) is not a directory", name, dir));
+            }
+            Ok(metadata) if metadata.permissions().readonly() => {
+                return Check::new("go paths", Status::Warn, format!("{} ({}) is read-only", name, dir))
+                    .hint("Go needs to write downloaded modules there");
+            }
+            _ => {}
+        }
+    }
+
+    Check::new("go paths", Status::Pass, format!("GOPATH {}, module cache {}", gopath, modcache))
+}
+
+fn check_config(project_dir: &Path) -> (Check, Option<GoaConfig>) {
+    match GoaConfig::load(project_dir.join("config.json")) {
+        Ok(config) if config.config_version.unwrap_or(0) < CURRENT_CONFIG_VERSION => (
+            Check::new(
+                "config",
+                Status::Warn,
+                format!("config.json is valid but uses config version {}", config.config_version.unwrap_or(0)),
+            )
+            .hint("Run `goa config migrate` to upgrade it"),
+            Some(config),
+        ),
+        Ok(config) => (Check::new("config", Status::Pass, "config.json is valid"), Some(config)),
+        Err(e) => (
+            Check::new("config", Status::Fail, e.to_string()).hint("Run `goa config validate` for details"),
+            None,
+        ),
+    }
+}
+
+fn check_main_file(project: &Project) -> Check {
+    if project.dir.join("main.go").exists() {
+        Check::new("main.go", Status::Pass, "main.go found")
+    } else {
+        Check::new("main.go", Status::Fail, format!("main.go is missing from {}", project.dir.display()))
+    }
+}
+
+fn check_layout(project: &Project) -> Check {
+    let config = match &project.config {
+        Some(config) => config,
+        None => return Check::new("layout", Status::Warn, "Skipped because config.json is invalid"),
+    };
+
+    let layout = &config.directories.layout_path;
+    if project.dir.join(layout).is_file() {
+        Check::new("layout", Status::Pass, format!("{} found", layout))
+    } else {
+        Check::new("layout", Status::Fail, format!("Layout template {} does not exist", layout))
+            .hint("Fix directories.layoutPath with `goa config set directories.layoutPath <path>`")
+    }
+}
+
+fn check_api_imports(project: &Project) -> Check {
+    let config = match &project.config {
+        Some(config) => config,
+        None => return Check::new("api routes", Status::Warn, "Skipped because config.json is invalid"),
+    };
+
+    let main_go = match fs::read_to_string(project.dir.join("main.go")) {
+        Ok(content) => content,
+        Err(_) => return Check::new("api routes", Status::Warn, "Skipped because main.go could not be read"),
+    };
+
+    let api_dir = project.dir.join(&config.directories.app_dir).join("api");
+    let mut routes = Vec::new();
+    for entry in WalkDir::new(&api_dir).into_iter().filter_map(|entry| entry.ok()) {
+        if entry.file_name() != "route.go" {
+            continue;
+        }
+        let route = entry.path().parent()
+            .and_then(|dir| dir.strip_prefix(&api_dir).ok())
+            .map(|dir| dir.to_string_lossy().replace('\\', "/"))
+            .unwrap_or_default();
+        if !route.is_empty() {
+            routes.push(route);
+        }
+    }
+
+    let missing: Vec<&String> = routes
+        .iter()
+        .filter(|route| {
+            let import_path = utils::api_import_path(&project.dir, &config.directories.app_dir, route);
+            !main_go.contains(&format!("\"{}\"", import_path))
+        })
+        .collect();
+
+    if missing.is_empty() {
+        Check::new("api routes", Status::Pass, format!("{} API route(s) registered in main.go", routes.len()))
+    } else {
+        Check::new(
+            "api routes",
+            Status::Warn,
+            format!(
+                "Not imported in main.go: {}",
+                missing.iter().map(|route| route.as_str()).collect::<Vec<_>>().join(", ")
+            ),
+        )
+        .hint("Recreate them with `goa route api new <path>` or add the imports to main.go")
+    }
+}
+
+fn check_port(project: &Project) -> Check {
+    let config = match &project.config {
+        Some(config) => config,
+        None => return Check::new("port", Status::Warn, "Skipped because config.json is invalid"),
+    };
+
+    let port = match config.server.port.parse::<u16>() {
+        Ok(port) => port,
+        Err(_) => return Check::new("port", Status::Fail, format!("server.port {} is not a valid port", config.server.port)),
+    };
+
+    match TcpListener::bind(("127.0.0.1", port)) {
+        Ok(_) => Check::new("port", Status::Pass, format!("Port {} is available", port)),
+        Err(_) => Check::new("port", Status::Warn, format!("Port {} is already in use", port))
+            .hint("Stop the process using it or change server.port"),
+    }
+}
+
+fn check_updates() -> Check {
+    let current = format!("v{}", version::get_current_version());
+    match version::get_update_status() {
+        Ok(UpdateStatus::Available(latest)) => Check::new(
+            "cli version",
+            Status::Warn,
+            format!("{} is installed, {} is available", current, latest),
+        )
+        .hint("Run `goa self update` to upgrade"),
+        Ok(UpdateStatus::Ahead(latest)) => Check::new(
+            "cli version",
+            Status::Pass,
+            format!("{} is a development build ahead of the latest release ({})", current, latest),
+        ),
+        Ok(UpdateStatus::UpToDate) => Check::new("cli version", Status::Pass, format!("{} is the latest version", current)),
+        Err(e) => Check::new("cli version", Status::Warn, format!("{} is installed; could not check for updates: {}", current, e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project_with(files: &[(&str, &str)]) -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        for (name, content) in files {
+            fs::write(dir.path().join(name), content).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn missing_go_fails_with_download_hint() {
+        let check = check_go_version(None, None);
+
+        assert!(check.status == Status::Fail);
+        assert_eq!(check.hint.as_deref(), Some("Install it from https://go.dev/dl/"));
+    }
+
+    #[test]
+    fn go_version_is_compared_with_go_mod() {
+        let project = project_with(&[("go.mod", "module example.com/app\n\ngo 1.22\n")]);
+
+        let old = check_go_version(Some("1.21.5"), Some(project.path()));
+        assert!(old.status == Status::Fail);
+        assert_eq!(old.message, "Go 1.21.5 is older than the 1.22 required by go.mod");
+        assert_eq!(old.hint.as_deref(), Some("Upgrade Go from https://go.dev/dl/"));
+
+        let new = check_go_version(Some("1.23.0"), Some(project.path()));
+        assert!(new.status == Status::Pass);
+        assert_eq!(new.message, "Go 1.23.0 (go.mod requires 1.22)");
+    }
+
+    #[test]
+    fn go_version_passes_without_go_mod() {
+        let project = project_with(&[]);
+
+        let check = check_go_version(Some("1.20"), Some(project.path()));
+        assert!(check.status == Status::Pass);
+        assert_eq!(check.message, "Go 1.20");
+    }
+
+    #[test]
+    fn config_check_reports_missing_and_outdated_configs() {
+        let missing = project_with(&[]);
+        let (check, config) = check_config(missing.path());
+        assert!(check.status == Status::Fail);
+        assert!(config.is_none());
+
+        let outdated = project_with(&[("config.json", "{}")]);
+        let (check, config) = check_config(outdated.path());
+        assert!(check.status == Status::Warn);
+        assert_eq!(check.hint.as_deref(), Some("Run `goa config migrate` to upgrade it"));
+        assert!(config.is_some());
+
+        let current = format!("{{\"configVersion\": {}}}", CURRENT_CONFIG_VERSION);
+        let valid = project_with(&[("config.json", &current)]);
+        let (check, _) = check_config(valid.path());
+        assert!(check.status == Status::Pass);
+    }
+
+    #[test]
+    fn config_check_fails_on_invalid_json() {
+        let project = project_with(&[("config.json", "{ not json")]);
+
+        let (check, config) = check_config(project.path());
+        assert!(check.status == Status::Fail);
+        assert!(config.is_none());
+    }
+}
@@ -1,3 +1,9 @@
+pub mod assets;
+pub mod completions;
 pub mod component;
+pub mod config;
+pub mod dev;
+pub mod doctor;
+pub mod env;
 pub mod project;
 pub mod route; 
\ No newline at end of file
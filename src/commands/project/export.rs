@@ -0,0 +1,309 @@
+use anyhow::Result;
+use colored::Colorize;
+use std::fs;
+use std::net::{TcpListener, TcpStream};
+use std::path::{Component, Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+use walkdir::WalkDir;
+
+use super::pages;
+use crate::config::{find_config_file, recover_backup, ConfigOverride, GoaConfig};
+use crate::errors::GoaError;
+use crate::utils;
+use crate::utils::logger;
+
+const SERVER_STARTUP_TIMEOUT: Duration = Duration::from_secs(30);
+
+struct ServerProcess(Child);
+
+impl Drop for ServerProcess {
+    fn drop(&mut self) {
+        self.0.kill().ok();
+        self.0.wait().ok();
+    }
+}
+
+pub fn export_project(output_dir: Option<String>, params: Vec<String>, params_file: Option<String>) -> Result<()> {
+    utils::log_step("Exporting Go on Airplanes project as a static site");
+
+    let config_path = find_config_file()?;
+    recover_backup(&config_path)?;
+    let config = GoaConfig::load(&config_path)?;
+    let project_dir = config_path.parent().unwrap().to_path_buf();
+
+    let app_path = project_dir.join(&config.directories.app_dir);
+    if !app_path.exists() {
+        return Err(GoaError::InvalidPath(format!("App directory not found at {}", app_path.display())).into());
+    }
+
+    let main_go_path = project_dir.join("main.go");
+    if !main_go_path.exists() {
+        return Err(GoaError::ProjectCreation("main.go not found in project directory".to_string()).into());
+    }
+
+    let route_params = pages::load_route_params(&params, params_file.as_deref())?;
+    let urls = resolve_export_urls(&pages::collect_page_routes(&app_path)?, &route_params);
+
+    if urls.is_empty() {
+        utils::log_warning("No page routes to export");
+        return Ok(());
+    }
+
+    let target_dir = match output_dir {
+        Some(dir) => PathBuf::from(dir),
+        None => project_dir.join(&config.ssg.directory),
+    };
+
+    utils::ensure_directory_exists(&target_dir)?;
+
+    let build_dir = std::env::temp_dir().join(format!("goa-export-{}", std::process::id()));
+    utils::ensure_directory_exists(&build_dir)?;
+
+    let result = build_and_crawl(&project_dir, &config_path, &config, &build_dir, &target_dir, &urls);
+    fs::remove_dir_all(&build_dir).ok();
+    let exported = result?;
+
+    let static_path = project_dir.join(&config.directories.static_dir);
+    let copied_assets = copy_static_assets(&static_path, &target_dir)?;
+
+    if logger::shows_decorations() {
+        println!("\n{}", "╭───────────────────────────────────────────────────╮".cyan());
+        println!("{}{:^53}{}", "│".cyan(), "EXPORT COMPLETED SUCCESSFULLY".green().bold(), "│".cyan());
+        println!("{}", "╰───────────────────────────────────────────────────╯".cyan());
+    }
+
+    utils::log_info(&format!("Pages exported: {}/{}", exported, urls.len()));
+    utils::log_info(&format!("Static assets copied: {}", copied_assets));
+    utils::log_info(&format!("Export output: {}", target_dir.display()));
+
+    Ok(())
+}
+
+fn resolve_export_urls(routes: &[String], route_params: &std::collections::BTreeMap<String, Vec<String>>) -> Vec<String> {
+    let mut urls = Vec::new();
+
+    for route in routes {
+        if !pages::is_dynamic_route(route) {
+            urls.push(route.clone());
+            continue;
+        }
+
+        match route_params.get(route) {
+            Some(values) => {
+                let expanded = pages::expand_route(route, values);
+                if expanded.len() < values.len() {
+                    utils::log_warning(&format!(
+                        "Some values for {} do not match its dynamic segments and were skipped",
+                        route
+                    ));
+                }
+                urls.extend(expanded);
+            }
+            None => utils::log_warning(&format!(
+                "Skipping dynamic route {} (pass values with --param \"{}=VALUE,...\")",
+                route, route
+            )),
+        }
+    }
+
+    for route in route_params.keys() {
+        if !routes.contains(route) {
+            utils::log_warning(&format!("Params were given for unknown page route {}", route));
+        }
+    }
+
+    urls
+}
+
+fn build_and_crawl(
+    project_dir: &Path,
+    config_path: &Path,
+    config: &GoaConfig,
+    build_dir: &Path,
+    target_dir: &Path,
+    urls: &[String],
+) -> Result<usize> {
+    utils::log_step("Building application for export...");
+
+    let executable_name = if cfg!(windows) { "app.exe" } else { "app" };
+    let executable_path = build_dir.join(executable_name);
+
+    let mut build = Command::new("go");
+    build.args(["build", "-o", &executable_path.to_string_lossy()]).current_dir(project_dir);
+    utils::log_command(&build);
+    let build_output = build
+        .output()
+        .map_err(|e| GoaError::ProjectCreation(format!("Failed to run build: {}", e)))?;
+
+    if !build_output.status.success() {
+        let error = String::from_utf8_lossy(&build_output.stderr);
+        return Err(GoaError::ProjectCreation(format!("Build failed: {}", error)).into());
+    }
+
+    let port = find_free_port()?;
+    let mut export_config = config.clone();
+    export_config.server.port = port.to_string();
+    export_config.server.dev_mode = false;
+    export_config.server.live_reload = false;
+    export_config.server.is_built_system = true;
+    let _config_override = ConfigOverride::apply(config_path, &export_config)?;
+
+    utils::log_step(&format!("Starting application on port {}...", port));
+
+    let mut app = Command::new(&executable_path);
+    app.current_dir(project_dir).stdout(Stdio::null()).stderr(Stdio::null());
+    utils::log_command(&app);
+    let child = app
+        .spawn()
+        .map_err(|e| GoaError::ProjectCreation(format!("Failed to start application: {}", e)))?;
+
+    let mut server = ServerProcess(child);
+    wait_for_server(&mut server, port)?;
+
+    utils::log_step(&format!("Rendering {} page(s)...", urls.len()));
+
+    let client = reqwest::blocking::Client::builder()
+        .timeout(Duration::from_secs(30))
+        .build()?;
+
+    let mut exported = 0;
+    for url in urls {
+        let address = format!("http://127.0.0.1:{}{}", port, pages::encode_url_path(url));
+
+        let response = match client.get(&address).send() {
+            Ok(response) => response,
+            Err(e) => {
+                utils::log_warning(&format!("Failed to fetch {}: {}", url, e));
+                continue;
+            }
+        };
+
+        if !response.status().is_success() {
+            utils::log_warning(&format!("Skipping {} (server responded with {})", url, response.status()));
+            continue;
+        }
+
+        let html = response.text()?;
+        utils::write_file(output_file_for_url(target_dir, url)?, &html)?;
+        exported += 1;
+    }
+
+    Ok(exported)
+}
+
+fn find_free_port() -> Result<u16> {
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .map_err(GoaError::Io)?;
+    Ok(listener.local_addr().map_err(GoaError::Io)?.port())
+}
+
+fn wait_for_server(server: &mut ServerProcess, port: u16) -> Result<()> {
+    let started = Instant::now();
+
+    while started.elapsed() < SERVER_STARTUP_TIMEOUT {
+        if let Some(status) = server.0.try_wait().map_err(GoaError::Io)? {
+            return Err(GoaError::ProjectCreation(format!("Application exited before it was ready ({})", status)).into());
+        }
+
+        if TcpStream::connect(("127.0.0.1", port)).is_ok() {
+            return Ok(());
+        }
+
+        thread::sleep(Duration::from_millis(200));
+    }
+
+    Err(GoaError::ProjectCreation(format!(
+        "Application did not start listening on port {} within {} seconds",
+        port,
+        SERVER_STARTUP_TIMEOUT.as_secs()
+    )).into())
+}
+
+fn output_file_for_url(target_dir: &Path, url: &str) -> Result<PathBuf> {
+    let mut path = target_dir.to_path_buf();
+    for part in url.split('/').filter(|s| !s.is_empty()) {
+        let is_plain = Path::new(part).components().all(|component| matches!(component, Component::Normal(_)));
+        if !is_plain || part.contains('\\') {
+            return Err(GoaError::InvalidPath(format!("Refusing to export {}: it does not map to a file inside the output directory", url)).into());
+        }
+        path.push(part);
+    }
+
+    let path = path.join("index.html");
+    if !path.starts_with(target_dir) {
+        return Err(GoaError::InvalidPath(format!("Refusing to write {} outside {}", path.display(), target_dir.display())).into());
+    }
+    Ok(path)
+}
+
+fn copy_static_assets(static_path: &Path, target_dir: &Path) -> Result<usize> {
+    if !static_path.exists() {
+        return Ok(0);
+    }
+
+    utils::log_step("Copying static assets...");
+
+    let static_name = static_path.file_name().map(PathBuf::from).unwrap_or_else(|| PathBuf::from("static"));
+    let destination = target_dir.join(static_name);
+    let target_dir = target_dir.canonicalize().map_err(GoaError::Io)?;
+
+    let mut copied = 0;
+    let entries = WalkDir::new(static_path)
+        .into_iter()
+        .filter_entry(|entry| {
+            entry.path().canonicalize().map(|p| p != target_dir).unwrap_or(true)
+        });
+
+    for entry in entries {
+        let entry = entry.map_err(|e| GoaError::Other(format!("Failed to read static assets: {}", e)))?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+
+        let relative = entry.path().strip_prefix(static_path).unwrap_or(entry.path());
+        let file_destination = destination.join(relative);
+
+        if let Some(parent) = file_destination.parent() {
+            fs::create_dir_all(parent).map_err(GoaError::Io)?;
+        }
+
+        fs::copy(entry.path(), &file_destination).map_err(GoaError::Io)?;
+        copied += 1;
+    }
+
+    Ok(copied)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn output_file_for_url_maps_routes_to_index_files() {
+        let target = Path::new("/out");
+
+        assert_eq!(output_file_for_url(target, "/").unwrap(), PathBuf::from("/out/index.html"));
+        assert_eq!(output_file_for_url(target, "/users/42").unwrap(), PathBuf::from("/out/users/42/index.html"));
+        assert_eq!(output_file_for_url(target, "/tags/a b").unwrap(), PathBuf::from("/out/tags/a b/index.html"));
+    }
+
+    #[test]
+    fn output_file_for_url_stays_inside_the_target() {
+        let target = Path::new("/out");
+
+        assert!(output_file_for_url(target, "/../etc").is_err());
+        assert!(output_file_for_url(target, "/users/./x").is_err());
+        assert!(output_file_for_url(target, "/users/a\\b").is_err());
+    }
+
+    #[test]
+    fn resolve_export_urls_expands_dynamic_routes() {
+        let routes = vec!["/".to_string(), "/users/[id]".to_string(), "/posts/[slug]".to_string()];
+        let mut params = std::collections::BTreeMap::new();
+        params.insert("/users/[id]".to_string(), vec!["1".to_string(), "2".to_string()]);
+
+        assert_eq!(resolve_export_urls(&routes, &params), vec!["/", "/users/1", "/users/2"]);
+    }
+}
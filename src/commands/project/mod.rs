@@ -1,13 +1,28 @@
 use anyhow::Result;
 use clap::Subcommand;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
-use serde_json::{Value, json};
 use colored::Colorize;
+use serde_json::json;
+use walkdir::WalkDir;
 
+use crate::config::{
+    find_config_file, CdnConfig, ConfigDocument, ConfigOverride, ConfigPath, GoaConfig, LayeredConfig, MetaConfig, PerformanceConfig,
+    ServerConfig, SsgConfig, PRODUCTION_ENV,
+};
 use crate::errors::GoaError;
+use crate::requirements::Requirement;
 use crate::utils;
+use crate::utils::logger;
+
+mod export;
+mod pages;
+mod relocate;
+mod sitemap;
+
+pub use pages::collect_page_routes;
+pub use relocate::{apply_path_changes, relocate_directory, MOVABLE_DIRECTORIES};
 
 #[derive(Subcommand)]
 pub enum ProjectCommands {
@@ -18,17 +33,73 @@ pub enum ProjectCommands {
     Config,
     
     Build {
-        #[clap(long, short)]
+        #[clap(long = "out-dir", short = 'o', value_name = "DIR", help = "Output directory for the build")]
         output: Option<String>,
+        
+        #[clap(long, value_name = "URL", help = "Write sitemap.xml and robots.txt for this base URL into the build output")]
+        base_url: Option<String>,
+        
+        #[clap(long = "param", value_name = "ROUTE=VALUES", requires = "base_url", help = "Values for a dynamic route in the sitemap, e.g. /users/[id]=1,2,3")]
+        params: Vec<String>,
+        
+        #[clap(long, value_name = "FILE", requires = "base_url", help = "JSON file mapping dynamic routes to lists of values")]
+        params_file: Option<String>,
+        
+        #[clap(long, default_value = PRODUCTION_ENV, help = "Environment whose config.<env>.json overlay is applied")]
+        env: String,
+        
+        #[clap(long, help = "Compile Tailwind CSS before building even if it has not been compiled yet")]
+        css: bool,
+        
+        #[clap(long, help = "Do not compile Tailwind CSS")]
+        no_css: bool,
+    },
+    
+    #[clap(about = "Export page routes as a static site into the SSG directory")]
+    Export {
+        #[clap(long = "out-dir", short = 'o', value_name = "DIR", help = "Output directory (defaults to ssg.directory)")]
+        output: Option<String>,
+        
+        #[clap(long = "param", value_name = "ROUTE=VALUES", help = "Values for a dynamic route, e.g. /users/[id]=1,2,3")]
+        params: Vec<String>,
+        
+        #[clap(long, value_name = "FILE", help = "JSON file mapping dynamic routes to lists of values")]
+        params_file: Option<String>,
+    },
+    
+    #[clap(about = "Generate sitemap.xml and robots.txt from page routes")]
+    Sitemap {
+        #[clap(long, value_name = "URL", help = "Public base URL of the site, e.g. https://example.com")]
+        base_url: String,
+        
+        #[clap(long = "param", value_name = "ROUTE=VALUES", help = "Values for a dynamic route, e.g. /users/[id]=1,2,3")]
+        params: Vec<String>,
+        
+        #[clap(long, value_name = "FILE", help = "JSON file mapping dynamic routes to lists of values")]
+        params_file: Option<String>,
     },
 }
 
+impl ProjectCommands {
+    pub fn requirements(&self) -> &'static [Requirement] {
+        match self {
+            ProjectCommands::New => &[Requirement::Git, Requirement::Go],
+            ProjectCommands::Build { .. } | ProjectCommands::Export { .. } => &[Requirement::Go],
+            _ => &[],
+        }
+    }
+}
+
 pub fn handle_project_command(command: ProjectCommands) -> Result<()> {
     match command {
         ProjectCommands::New => create_new_project(),
         ProjectCommands::List => list_project_routes(),
         ProjectCommands::Config => configure_project(),
-        ProjectCommands::Build { output } => build_project(output),
+        ProjectCommands::Build { output, base_url, params, params_file, env, css, no_css } => {
+            build_project(output, base_url, params, params_file, &env, css, no_css)
+        },
+        ProjectCommands::Export { output, params, params_file } => export::export_project(output, params, params_file),
+        ProjectCommands::Sitemap { base_url, params, params_file } => sitemap::generate_sitemap(base_url, params, params_file),
     }
 }
 
@@ -53,21 +124,26 @@ fn create_new_project() -> Result<()> {
     
     
     #[cfg(windows)]
-    let setup_result = if Command::new("where").arg("bash").output().is_ok() {
-        Command::new("bash")
-            .args(["-c", &format!("git clone https://github.com/kleeedolinux/goonairplanes.git {}", project_dir)])
-            .output()
+    let mut setup = if Command::new("where").arg("bash").output().is_ok() {
+        let mut command = Command::new("bash");
+        command.args(["-c", &format!("git clone https://github.com/kleeedolinux/goonairplanes.git {}", project_dir)]);
+        command
     } else {
-        Command::new("powershell")
-            .args(["-Command", &format!("git clone https://github.com/kleeedolinux/goonairplanes.git {}", project_dir)])
-            .output()
+        let mut command = Command::new("powershell");
+        command.args(["-Command", &format!("git clone https://github.com/kleeedolinux/goonairplanes.git {}", project_dir)]);
+        command
     };
     
     
     #[cfg(not(windows))]
-    let setup_result = Command::new("bash")
-        .args(["-c", &format!("git clone https://github.com/kleeedolinux/goonairplanes.git {}", project_dir)])
-        .output();
+    let mut setup = {
+        let mut command = Command::new("bash");
+        command.args(["-c", &format!("git clone https://github.com/kleeedolinux/goonairplanes.git {}", project_dir)]);
+        command
+    };
+    
+    utils::log_command(&setup);
+    let setup_result = setup.output();
     
     match setup_result {
         Ok(output) => {
@@ -82,10 +158,10 @@ fn create_new_project() -> Result<()> {
                 update_config_meta(&config_path, &project_name, &project_description)?;
                 
                 
-                let git_init = Command::new("git")
-                    .args(["init"])
-                    .current_dir(&project_dir)
-                    .output();
+                let mut git_init = Command::new("git");
+                git_init.args(["init"]).current_dir(&project_dir);
+                utils::log_command(&git_init);
+                let git_init = git_init.output();
                 
                 if let Ok(git_output) = git_init {
                     if git_output.status.success() {
@@ -94,10 +170,10 @@ fn create_new_project() -> Result<()> {
                 }
                 
                 
-                let go_tidy = Command::new("go")
-                    .args(["mod", "tidy"])
-                    .current_dir(&project_dir)
-                    .output();
+                let mut go_tidy = Command::new("go");
+                go_tidy.args(["mod", "tidy"]).current_dir(&project_dir);
+                utils::log_command(&go_tidy);
+                let go_tidy = go_tidy.output();
                 
                 if let Ok(go_output) = go_tidy {
                     if go_output.status.success() {
@@ -114,12 +190,10 @@ fn create_new_project() -> Result<()> {
                 Ok(())
             } else {
                 let error = String::from_utf8_lossy(&output.stderr);
-                utils::log_error(&format!("Failed to clone repository: {}", error));
                 Err(GoaError::ProjectCreation(format!("Failed to clone repository: {}", error)).into())
             }
         }
         Err(e) => {
-            utils::log_error(&format!("Failed to run setup: {}", e));
             Err(GoaError::ProjectCreation(format!("Failed to run setup: {}", e)).into())
         }
     }
@@ -183,39 +257,14 @@ fn cleanup_files(project_dir: PathBuf, keep_docs: bool) -> Result<()> {
     Ok(())
 }
 
-fn update_config_meta(config_path: &PathBuf, project_name: &str, project_description: &str) -> Result<()> {
-    if !config_path.exists() {
-        return Err(GoaError::Configuration(format!("Config file not found at {}", config_path.display())).into());
-    }
-    
-    
-    let config_str = fs::read_to_string(config_path)
-        .map_err(|e| GoaError::Io(e))?;
-    
-    
-    let mut config: Value = serde_json::from_str(&config_str)
-        .map_err(|e| GoaError::Json(e))?;
-    
-    
-    if let Some(meta) = config.get_mut("meta") {
-        if let Some(meta_obj) = meta.as_object_mut() {
-            meta_obj.insert("appName".to_string(), json!(project_name));
-            
-            if let Some(meta_tags) = meta_obj.get_mut("defaultMetaTags") {
-                if let Some(meta_tags_obj) = meta_tags.as_object_mut() {
-                    meta_tags_obj.insert("description".to_string(), json!(project_description));
-                    meta_tags_obj.insert("og:title".to_string(), json!(project_name));
-                }
-            }
-        }
-    }
+fn update_config_meta(config_path: &Path, project_name: &str, project_description: &str) -> Result<()> {
+    let mut config = GoaConfig::load(config_path)?;
     
+    config.meta.app_name = project_name.to_string();
+    config.meta.default_meta_tags.description = project_description.to_string();
+    config.meta.default_meta_tags.og_title = project_name.to_string();
     
-    let updated_config = serde_json::to_string_pretty(&config)
-        .map_err(|e| GoaError::Json(e))?;
-    
-    fs::write(config_path, updated_config)
-        .map_err(|e| GoaError::Io(e))?;
+    config.save(config_path)?;
     
     utils::log_success("Updated project configuration");
     Ok(())
@@ -225,49 +274,33 @@ fn list_project_routes() -> Result<()> {
     utils::log_step("Analyzing project structure");
     
     let config_path = find_config_file()?;
-    let config = fs::read_to_string(&config_path)
-        .map_err(|e| GoaError::Io(e))?;
-    
-    let config: Value = serde_json::from_str(&config)
-        .map_err(|e| GoaError::Json(e))?;
-    
-    let app_dir = if let Some(dirs) = config.get("directories") {
-        if let Some(app_dir) = dirs.get("appDir") {
-            app_dir.as_str().unwrap_or("app").to_string()
-        } else {
-            "app".to_string()
-        }
-    } else {
-        "app".to_string()
-    };
-    
-    let app_name = if let Some(meta) = config.get("meta") {
-        if let Some(name) = meta.get("appName") {
-            name.as_str().unwrap_or("Go on Airplanes").to_string()
-        } else {
-            "Go on Airplanes".to_string()
-        }
-    } else {
-        "Go on Airplanes".to_string()
-    };
+    let config = GoaConfig::load(&config_path)?;
     
     let project_dir = config_path.parent().unwrap().to_path_buf();
-    let app_path = project_dir.join(&app_dir);
+    let app_path = project_dir.join(&config.directories.app_dir);
     
     if !app_path.exists() {
-        utils::log_error(&format!("App directory not found at {}", app_path.display()));
         return Err(GoaError::InvalidPath(format!("App directory not found at {}", app_path.display())).into());
     }
     
+    if logger::is_json() {
+        logger::set_result(json!({
+            "appName": config.meta.app_name,
+            "apiRoutes": api_route_names(&app_path)?,
+            "pageRoutes": pages::collect_page_routes(&app_path)?,
+            "components": component_names(&project_dir, &config)?,
+        }));
+        return Ok(());
+    }
     
     println!("\n{}", "╭───────────────────────────────────────────────────╮".cyan());
-    println!("{}{:^53}{}", "│".cyan(), app_name.bold(), "│".cyan());
+    println!("{}{:^53}{}", "│".cyan(), config.meta.app_name.bold(), "│".cyan());
     println!("{}", "╰───────────────────────────────────────────────────╯".cyan());
     
     
     list_api_routes(&app_path)?;
     list_page_routes(&app_path)?;
-    list_components(&app_path, &config)?;
+    list_components(&project_dir, &config)?;
     
     Ok(())
 }
@@ -336,66 +369,17 @@ fn list_api_routes(app_path: &PathBuf) -> Result<()> {
 fn list_page_routes(app_path: &PathBuf) -> Result<()> {
     println!("\n{}", format!("╭─ PAGE ROUTES {}", "─".repeat(38)).magenta().bold());
     
-    let routes = find_routes_in_directory(app_path, "index.html", |path| {
-        !path.to_string_lossy().contains("/components/") && 
-        !path.to_string_lossy().contains("/api/")
-    })?;
+    let routes = pages::collect_page_routes(app_path)?;
     
     if routes.is_empty() {
         println!("│  {}", "(none)".dimmed());
     } else {
-        
-        let mut route_tree: std::collections::BTreeMap<String, Vec<String>> = std::collections::BTreeMap::new();
-        
-        for route_path in routes {
-            let relative_path = route_path.strip_prefix(app_path).unwrap_or(&route_path);
-            let parent = relative_path.parent().unwrap_or(relative_path);
-            let route_str = parent.to_string_lossy().to_string();
-            
-            
-            if route_str.is_empty() {
-                route_tree.entry("/".to_string()).or_insert(vec![]);
-                continue;
-            }
-            
-            
-            let parts: Vec<&str> = route_str.split('/').filter(|s| !s.is_empty()).collect();
-            if parts.is_empty() {
-                route_tree.entry("/".to_string()).or_insert(vec![]);
-            } else {
-                let mut current_path = String::new();
-                for (i, part) in parts.iter().enumerate() {
-                    if i > 0 {
-                        current_path.push('/');
-                    }
-                    current_path.push_str(part);
-                    
-                    if i == parts.len() - 1 {
-                        let route_key = if current_path.is_empty() { 
-                            "/".to_string() 
-                        } else { 
-                            format!("/{}", current_path) 
-                        };
-                        route_tree.entry(route_key).or_insert(vec![]);
-                    }
-                }
-            }
-        }
-        
-        
-        for (route, _) in route_tree.iter_mut() {
-            if route.contains('[') && route.contains(']') {
-                
-            }
-        }
-        
-        
-        for (i, (route, _)) in route_tree.iter().enumerate() {
-            let is_last = i == route_tree.len() - 1;
+        for (i, route) in routes.iter().enumerate() {
+            let is_last = i == routes.len() - 1;
             let prefix = if is_last { "└─ " } else { "├─ " };
             
             
-            if route.contains('[') && route.contains(']') {
+            if pages::is_dynamic_route(route) {
                 println!("│ {}{}", prefix.magenta(), route.yellow().bold().italic());
             } else {
                 println!("│ {}{}", prefix.magenta(), route.yellow().bold());
@@ -407,21 +391,10 @@ fn list_page_routes(app_path: &PathBuf) -> Result<()> {
     Ok(())
 }
 
-fn list_components(app_path: &PathBuf, config: &Value) -> Result<()> {
+fn list_components(project_dir: &Path, config: &GoaConfig) -> Result<()> {
     println!("\n{}", format!("╭─ COMPONENTS {}", "─".repeat(39)).bright_blue().bold());
     
-    let component_dir = if let Some(dirs) = config.get("directories") {
-        if let Some(dir) = dirs.get("componentDir") {
-            dir.as_str().unwrap_or("app/components").to_string()
-        } else {
-            "app/components".to_string()
-        }
-    } else {
-        "app/components".to_string()
-    };
-    
-    let project_dir = app_path.parent().unwrap_or(app_path);
-    let components_path = project_dir.join(&component_dir);
+    let components_path = project_dir.join(&config.directories.component_dir);
     
     if !components_path.exists() {
         println!("│  {}", "(none)".dimmed());
@@ -450,20 +423,47 @@ fn list_components(app_path: &PathBuf, config: &Value) -> Result<()> {
     Ok(())
 }
 
-fn find_routes_in_directory<F>(base_dir: &PathBuf, target_file: &str, filter: F) -> Result<Vec<PathBuf>>
+fn api_route_names(app_path: &Path) -> Result<Vec<String>> {
+    let api_path = app_path.join("api");
+    let mut routes: Vec<String> = find_routes_in_directory(&api_path, "route.go", |path| {
+        !path.to_string_lossy().contains("/components/")
+    })?
+        .iter()
+        .filter_map(|path| path.parent()?.strip_prefix(&api_path).ok().map(|dir| dir.to_string_lossy().replace('\\', "/")))
+        .filter(|route| !route.is_empty())
+        .map(|route| format!("/{}", route))
+        .collect();
+    routes.sort();
+    Ok(routes)
+}
+
+fn component_names(project_dir: &Path, config: &GoaConfig) -> Result<Vec<String>> {
+    let components_path = project_dir.join(&config.directories.component_dir);
+    let mut names: Vec<String> = WalkDir::new(&components_path)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.into_path())
+        .filter(|path| path.extension().map(|ext| ext == "html").unwrap_or(false))
+        .filter_map(|path| path.file_stem().map(|stem| stem.to_string_lossy().into_owned()))
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
+fn find_routes_in_directory<F>(base_dir: &Path, target_file: &str, filter: F) -> Result<Vec<PathBuf>>
 where
     F: Fn(&PathBuf) -> bool,
 {
     let mut result = Vec::new();
-    let mut dirs_to_scan = vec![base_dir.clone()];
+    let mut dirs_to_scan = vec![base_dir.to_path_buf()];
     
     while let Some(current_dir) = dirs_to_scan.pop() {
         if !current_dir.is_dir() {
             continue;
         }
         
-        for entry in fs::read_dir(&current_dir).map_err(|e| GoaError::Io(e))? {
-            let entry = entry.map_err(|e| GoaError::Io(e))?;
+        for entry in fs::read_dir(&current_dir).map_err(GoaError::Io)? {
+            let entry = entry.map_err(GoaError::Io)?;
             let path = entry.path();
             
             if path.is_dir() {
@@ -480,35 +480,12 @@ where
     Ok(result)
 }
 
-fn find_config_file() -> Result<PathBuf> {
-    let current_dir = std::env::current_dir().map_err(|e| GoaError::Io(e))?;
-    let config_path = current_dir.join("config.json");
-    
-    if config_path.exists() {
-        return Ok(config_path);
-    }
-    
-    let mut dir = current_dir;
-    while let Some(parent) = dir.parent() {
-        let parent_config = parent.join("config.json");
-        if parent_config.exists() {
-            return Ok(parent_config);
-        }
-        dir = parent.to_path_buf();
-    }
-    
-    Err(GoaError::Configuration("Could not find config.json file. Are you inside a Go on Airplanes project?".to_string()).into())
-}
-
 fn configure_project() -> Result<()> {
     utils::log_step("Configuring Go on Airplanes project");
     
     let config_path = find_config_file()?;
-    let config_str = fs::read_to_string(&config_path)
-        .map_err(|e| GoaError::Io(e))?;
-    
-    let mut config: Value = serde_json::from_str(&config_str)
-        .map_err(|e| GoaError::Json(e))?;
+    let project_dir = config_path.parent().unwrap().to_path_buf();
+    let mut config = GoaConfig::load(&config_path)?;
     
     let categories = vec![
         "Server Settings",
@@ -516,6 +493,7 @@ fn configure_project() -> Result<()> {
         "Performance",
         "Static Site Generation (SSG)",
         "Meta Information",
+        "CDN Libraries",
         "Save and Exit"
     ];
     
@@ -527,267 +505,284 @@ fn configure_project() -> Result<()> {
             println!("  {}. {}", (i + 1).to_string().cyan(), category);
         }
         
-        let selection = utils::prompt_input("Select category (1-6)", Some("6".to_string()))?;
-        let selection = selection.parse::<usize>().unwrap_or(6);
+        let selection = utils::prompt_input("Select category (1-7)", Some("7".to_string()))?;
+        let selection = selection.parse::<usize>().unwrap_or(7);
         
         match selection {
-            1 => configure_server_settings(&mut config)?,
-            2 => configure_directory_paths(&mut config)?,
-            3 => configure_performance(&mut config)?,
-            4 => configure_ssg(&mut config)?,
-            5 => configure_meta(&mut config)?,
+            1 => configure_server_settings(&mut config.server)?,
+            2 => configure_directory_paths(&mut config, &config_path)?,
+            3 => configure_performance(&mut config.performance)?,
+            4 => configure_ssg(&mut config.ssg, &project_dir)?,
+            5 => configure_meta(&mut config.meta)?,
+            6 => configure_cdn(&mut config.cdn)?,
             _ => break,
         }
     }
     
-    let updated_config = serde_json::to_string_pretty(&config)
-        .map_err(|e| GoaError::Json(e))?;
-    
-    fs::write(&config_path, updated_config)
-        .map_err(|e| GoaError::Io(e))?;
+    config.save(&config_path)?;
     
     utils::log_success("Configuration saved successfully");
     Ok(())
 }
 
-fn configure_server_settings(config: &mut Value) -> Result<()> {
+fn configure_server_settings(server: &mut ServerConfig) -> Result<()> {
     println!("\n{}", "SERVER SETTINGS".bold().underline());
     
-    let server = config.get_mut("server").and_then(|s| s.as_object_mut());
+    server.port = utils::prompt_validated_input("Port", Some(server.port.clone()), utils::validate_port)?;
+    server.dev_mode = utils::prompt_confirm("Enable development mode", server.dev_mode)?;
+    server.is_built_system = utils::prompt_confirm("Is built system", server.is_built_system)?;
+    server.live_reload = utils::prompt_confirm("Enable live reload", server.live_reload)?;
+    server.enable_cors = utils::prompt_confirm("Enable CORS", server.enable_cors)?;
     
-    if let Some(server) = server {
-        
-        let current_port = server.get("port").and_then(|v| v.as_str()).unwrap_or("5000");
-        let new_port = utils::prompt_input("Port", Some(current_port.to_string()))?;
-        server.insert("port".to_string(), json!(new_port));
-        
-        
-        let current_dev_mode = server.get("devMode").and_then(|v| v.as_bool()).unwrap_or(true);
-        let new_dev_mode = utils::prompt_confirm("Enable development mode", current_dev_mode)?;
-        server.insert("devMode".to_string(), json!(new_dev_mode));
-        
-        
-        let current_built = server.get("isBuiltSystem").and_then(|v| v.as_bool()).unwrap_or(false);
-        let new_built = utils::prompt_confirm("Is built system", current_built)?;
-        server.insert("isBuiltSystem".to_string(), json!(new_built));
-        
-        
-        let current_live_reload = server.get("liveReload").and_then(|v| v.as_bool()).unwrap_or(true);
-        let new_live_reload = utils::prompt_confirm("Enable live reload", current_live_reload)?;
-        server.insert("liveReload".to_string(), json!(new_live_reload));
-        
-        
-        let current_cors = server.get("enableCORS").and_then(|v| v.as_bool()).unwrap_or(false);
-        let new_cors = utils::prompt_confirm("Enable CORS", current_cors)?;
-        server.insert("enableCORS".to_string(), json!(new_cors));
-        
-        
-        let current_rate_limit = server.get("rateLimit").and_then(|v| v.as_u64()).unwrap_or(100);
-        let new_rate_limit = utils::prompt_input("Rate limit", Some(current_rate_limit.to_string()))?;
-        let new_rate_limit = new_rate_limit.parse::<u64>().unwrap_or(100);
-        server.insert("rateLimit".to_string(), json!(new_rate_limit));
-        
-        utils::log_success("Server settings updated");
-    } else {
-        utils::log_error("Server configuration section not found");
+    if server.enable_cors {
+        println!("Allowed origins: {}", server.allowed_origins.join(", ").cyan());
+        if utils::prompt_confirm("Edit allowed origins", false)? {
+            configure_allowed_origins(&mut server.allowed_origins)?;
+        }
     }
     
+    let new_rate_limit = utils::prompt_validated_input(
+        "Rate limit",
+        Some(server.rate_limit.to_string()),
+        utils::validate_positive_integer,
+    )?;
+    server.rate_limit = new_rate_limit.parse::<u32>()?;
+    
+    utils::log_success("Server settings updated");
     Ok(())
 }
 
-fn configure_directory_paths(config: &mut Value) -> Result<()> {
-    println!("\n{}", "DIRECTORY PATHS".bold().underline());
-    
-    let directories = config.get_mut("directories").and_then(|d| d.as_object_mut());
+fn configure_allowed_origins(origins: &mut Vec<String>) -> Result<()> {
+    let actions = vec![
+        "Add origin".to_string(),
+        "Remove origin".to_string(),
+        "Move origin".to_string(),
+        "Done".to_string(),
+    ];
     
-    if let Some(directories) = directories {
-        
-        let current_app_dir = directories.get("appDir").and_then(|v| v.as_str()).unwrap_or("app");
-        let new_app_dir = utils::prompt_input("App directory", Some(current_app_dir.to_string()))?;
-        directories.insert("appDir".to_string(), json!(new_app_dir));
-        
-        
-        let current_static_dir = directories.get("staticDir").and_then(|v| v.as_str()).unwrap_or("static");
-        let new_static_dir = utils::prompt_input("Static directory", Some(current_static_dir.to_string()))?;
-        directories.insert("staticDir".to_string(), json!(new_static_dir));
-        
-        
-        let current_layout = directories.get("layoutPath").and_then(|v| v.as_str()).unwrap_or("app/layout.html");
-        let new_layout = utils::prompt_input("Layout path", Some(current_layout.to_string()))?;
-        directories.insert("layoutPath".to_string(), json!(new_layout));
-        
-        
-        let current_component_dir = directories.get("componentDir").and_then(|v| v.as_str()).unwrap_or("app/components");
-        let new_component_dir = utils::prompt_input("Component directory", Some(current_component_dir.to_string()))?;
-        directories.insert("componentDir".to_string(), json!(new_component_dir));
+    loop {
+        println!("\n{}", "Allowed origins:".bold());
+        if origins.is_empty() {
+            println!("  (none)");
+        }
+        for (i, origin) in origins.iter().enumerate() {
+            println!("  {}. {}", (i + 1).to_string().cyan(), origin);
+        }
         
-        utils::log_success("Directory paths updated");
-    } else {
-        utils::log_error("Directories configuration section not found");
+        match utils::prompt_select("Choose an action", &actions)? {
+            0 => {
+                let origin = utils::prompt_validated_input("New origin", None, utils::validate_origin)?;
+                if origins.contains(&origin) {
+                    utils::log_warning(&format!("{} is already allowed", origin));
+                } else {
+                    origins.push(origin);
+                }
+            }
+            1 if !origins.is_empty() => {
+                let index = utils::prompt_select("Origin to remove", origins)?;
+                let removed = origins.remove(index);
+                utils::log_info(&format!("Removed {}", removed));
+            }
+            2 if origins.len() > 1 => {
+                let index = utils::prompt_select("Origin to move", origins)?;
+                let count = origins.len();
+                let position = utils::prompt_validated_input(
+                    format!("New position (1-{})", count),
+                    Some((index + 1).to_string()),
+                    |value| match value.parse::<usize>() {
+                        Ok(position) if (1..=count).contains(&position) => Ok(()),
+                        _ => Err(format!("Enter a number between 1 and {}", count)),
+                    },
+                )?;
+                let origin = origins.remove(index);
+                origins.insert(position.parse::<usize>()? - 1, origin);
+            }
+            1 | 2 => utils::log_warning("Not enough origins for that action"),
+            _ => break,
+        }
+    }
+    
+    if origins.is_empty() {
+        utils::log_warning("No allowed origins are set; cross-origin requests will be rejected");
     }
     
     Ok(())
 }
 
-fn configure_performance(config: &mut Value) -> Result<()> {
-    println!("\n{}", "PERFORMANCE SETTINGS".bold().underline());
-    
-    let performance = config.get_mut("performance").and_then(|p| p.as_object_mut());
+fn configure_directory_paths(config: &mut GoaConfig, config_path: &Path) -> Result<()> {
+    let project_dir = config_path.parent().unwrap_or(Path::new("."));
+    println!("\n{}", "DIRECTORY PATHS".bold().underline());
     
-    if let Some(performance) = performance {
-        
-        let current_template_cache = performance.get("templateCache").and_then(|v| v.as_bool()).unwrap_or(true);
-        let new_template_cache = utils::prompt_confirm("Enable template cache", current_template_cache)?;
-        performance.insert("templateCache".to_string(), json!(new_template_cache));
-        
-        
-        let current_in_memory_js = performance.get("inMemoryJS").and_then(|v| v.as_bool()).unwrap_or(true);
-        let new_in_memory_js = utils::prompt_confirm("Enable in-memory JavaScript", current_in_memory_js)?;
-        performance.insert("inMemoryJS".to_string(), json!(new_in_memory_js));
-        
-        utils::log_success("Performance settings updated");
-    } else {
-        utils::log_error("Performance configuration section not found");
-    }
+    let app_dir = utils::prompt_validated_input("App directory", Some(config.directories.app_dir.clone()), |value| {
+        utils::validate_directory(project_dir, value)
+    })?;
+    move_or_create_directory(config, config_path, "directories.appDir", &app_dir)?;
+    
+    let directories = &mut config.directories;
+    directories.static_dir = prompt_directory("Static directory", &directories.static_dir, project_dir)?;
+    directories.layout_path = utils::prompt_validated_input(
+        "Layout path",
+        Some(directories.layout_path.clone()),
+        |value| utils::validate_layout_path(project_dir, value),
+    )?;
+    
+    let component_dir = utils::prompt_validated_input("Component directory", Some(config.directories.component_dir.clone()), |value| {
+        utils::validate_directory(project_dir, value)
+    })?;
+    move_or_create_directory(config, config_path, "directories.componentDir", &component_dir)?;
     
+    utils::log_success("Directory paths updated");
     Ok(())
 }
 
-fn configure_ssg(config: &mut Value) -> Result<()> {
-    println!("\n{}", "STATIC SITE GENERATION (SSG) SETTINGS".bold().underline());
+fn move_or_create_directory(config: &mut GoaConfig, config_path: &Path, key: &'static str, new_dir: &str) -> Result<()> {
+    let project_dir = config_path.parent().unwrap_or(Path::new("."));
+    let current = match key {
+        "directories.appDir" => config.directories.app_dir.clone(),
+        _ => config.directories.component_dir.clone(),
+    };
     
-    let ssg = config.get_mut("ssg").and_then(|s| s.as_object_mut());
+    let current_path = project_dir.join(&current);
+    if new_dir != current && current_path.exists() && utils::prompt_confirm(
+        format!("Move the existing files from {} to {}", current, new_dir),
+        true,
+    )? {
+        let changes = relocate_directory(project_dir, config, key, new_dir, false, |changes| {
+            save_path_changes(config_path, changes)
+        })?;
+        apply_path_changes(config, &changes);
+        return Ok(());
+    }
     
-    if let Some(ssg) = ssg {
-        
-        let current_enabled = ssg.get("enabled").and_then(|v| v.as_bool()).unwrap_or(true);
-        let new_enabled = utils::prompt_confirm("Enable SSG", current_enabled)?;
-        ssg.insert("enabled".to_string(), json!(new_enabled));
-        
-        
-        let current_cache = ssg.get("cacheEnabled").and_then(|v| v.as_bool()).unwrap_or(true);
-        let new_cache = utils::prompt_confirm("Enable SSG cache", current_cache)?;
-        ssg.insert("cacheEnabled".to_string(), json!(new_cache));
-        
-        
-        let current_directory = ssg.get("directory").and_then(|v| v.as_str()).unwrap_or("static/generated");
-        let new_directory = utils::prompt_input("SSG output directory", Some(current_directory.to_string()))?;
-        ssg.insert("directory".to_string(), json!(new_directory));
-        
-        utils::log_success("SSG settings updated");
-    } else {
-        utils::log_error("SSG configuration section not found");
+    let path = project_dir.join(new_dir);
+    if !path.exists() && utils::prompt_confirm(format!("{} does not exist. Create it now", new_dir), true)? {
+        utils::ensure_directory_exists(&path)?;
     }
     
+    apply_path_changes(config, &[(key, new_dir.to_string())]);
     Ok(())
 }
 
-fn configure_meta(config: &mut Value) -> Result<()> {
-    println!("\n{}", "META INFORMATION".bold().underline());
-    
-    let meta = config.get_mut("meta").and_then(|m| m.as_object_mut());
-    
-    if let Some(meta) = meta {
-        
-        let current_app_name = meta.get("appName").and_then(|v| v.as_str()).unwrap_or("Go on Airplanes");
-        let new_app_name = utils::prompt_input("Application name", Some(current_app_name.to_string()))?;
-        meta.insert("appName".to_string(), json!(new_app_name));
-        
-        
-        let meta_tags = meta.get_mut("defaultMetaTags").and_then(|t| t.as_object_mut());
-        
-        if let Some(meta_tags) = meta_tags {
-            println!("\n{}", "Default Meta Tags:".bold());
-            
-            
-            let current_viewport = meta_tags.get("viewport").and_then(|v| v.as_str()).unwrap_or("width=device-width, initial-scale=1.0");
-            let new_viewport = utils::prompt_input("Viewport", Some(current_viewport.to_string()))?;
-            meta_tags.insert("viewport".to_string(), json!(new_viewport));
-            
-            
-            let current_desc = meta_tags.get("description").and_then(|v| v.as_str()).unwrap_or("Go on Airplanes - A modern Go web framework");
-            let new_desc = utils::prompt_input("Description", Some(current_desc.to_string()))?;
-            meta_tags.insert("description".to_string(), json!(new_desc));
-            
-            
-            let current_og_title = meta_tags.get("og:title").and_then(|v| v.as_str()).unwrap_or(&new_app_name);
-            let new_og_title = utils::prompt_input("Open Graph title", Some(current_og_title.to_string()))?;
-            meta_tags.insert("og:title".to_string(), json!(new_og_title));
-            
-            
-            let current_og_type = meta_tags.get("og:type").and_then(|v| v.as_str()).unwrap_or("website");
-            let new_og_type = utils::prompt_input("Open Graph type", Some(current_og_type.to_string()))?;
-            meta_tags.insert("og:type".to_string(), json!(new_og_type));
-            
-            
-            let current_twitter = meta_tags.get("twitter:card").and_then(|v| v.as_str()).unwrap_or("summary");
-            let new_twitter = utils::prompt_input("Twitter card type", Some(current_twitter.to_string()))?;
-            meta_tags.insert("twitter:card".to_string(), json!(new_twitter));
-        }
-        
-        utils::log_success("Meta information updated");
-    } else {
-        utils::log_error("Meta configuration section not found");
+fn save_path_changes(config_path: &Path, changes: &[(&'static str, String)]) -> Result<()> {
+    let mut document = ConfigDocument::load(config_path)?;
+    for (setting, value) in changes {
+        document.set(&ConfigPath::parse(setting)?, value)?;
     }
+    document.save()?;
     
+    utils::log_info("Saved the new directory paths to config.json so it matches the moved files");
     Ok(())
 }
 
-fn build_project(output_dir: Option<String>) -> Result<()> {
-    utils::log_step("Building Go on Airplanes project for production");
+fn prompt_directory(prompt: &str, current: &str, project_dir: &Path) -> Result<String> {
+    let dir = utils::prompt_validated_input(prompt, Some(current.to_string()), |value| {
+        utils::validate_directory(project_dir, value)
+    })?;
     
+    let path = project_dir.join(&dir);
+    if !path.exists() && utils::prompt_confirm(format!("{} does not exist. Create it now", dir), true)? {
+        utils::ensure_directory_exists(&path)?;
+    }
     
-    let config_path = find_config_file()?;
+    Ok(dir)
+}
+
+fn configure_performance(performance: &mut PerformanceConfig) -> Result<()> {
+    println!("\n{}", "PERFORMANCE SETTINGS".bold().underline());
     
+    performance.template_cache = utils::prompt_confirm("Enable template cache", performance.template_cache)?;
+    performance.in_memory_js = utils::prompt_confirm("Enable in-memory JavaScript", performance.in_memory_js)?;
     
-    let project_dir = config_path.parent().unwrap().to_path_buf();
+    utils::log_success("Performance settings updated");
+    Ok(())
+}
+
+fn configure_ssg(ssg: &mut SsgConfig, project_dir: &Path) -> Result<()> {
+    println!("\n{}", "STATIC SITE GENERATION (SSG) SETTINGS".bold().underline());
     
+    ssg.enabled = utils::prompt_confirm("Enable SSG", ssg.enabled)?;
+    ssg.cache_enabled = utils::prompt_confirm("Enable SSG cache", ssg.cache_enabled)?;
+    ssg.directory = prompt_directory("SSG output directory", &ssg.directory, project_dir)?;
     
-    let temp_config_path = project_dir.join("temp_production_config.json");
+    utils::log_success("SSG settings updated");
+    Ok(())
+}
+
+fn configure_meta(meta: &mut MetaConfig) -> Result<()> {
+    println!("\n{}", "META INFORMATION".bold().underline());
     
+    meta.app_name = utils::prompt_input("Application name", Some(meta.app_name.clone()))?;
     
-    let config_str = fs::read_to_string(&config_path)
-        .map_err(|e| GoaError::Io(e))?;
+    let meta_tags = &mut meta.default_meta_tags;
+    println!("\n{}", "Default Meta Tags:".bold());
     
-    let mut config: Value = serde_json::from_str(&config_str)
-        .map_err(|e| GoaError::Json(e))?;
+    meta_tags.viewport = utils::prompt_input("Viewport", Some(meta_tags.viewport.clone()))?;
+    meta_tags.description = utils::prompt_input("Description", Some(meta_tags.description.clone()))?;
     
+    let current_og_title = if meta_tags.og_title.is_empty() { meta.app_name.clone() } else { meta_tags.og_title.clone() };
+    meta_tags.og_title = utils::prompt_input("Open Graph title", Some(current_og_title))?;
+    meta_tags.og_type = utils::prompt_input("Open Graph type", Some(meta_tags.og_type.clone()))?;
+    meta_tags.twitter_card = utils::prompt_input("Twitter card type", Some(meta_tags.twitter_card.clone()))?;
     
-    let server = config.get_mut("server").and_then(|s| s.as_object_mut());
-    if let Some(server) = server {
-        
-        server.insert("devMode".to_string(), json!(false));
-        
-        
-        server.insert("liveReload".to_string(), json!(false));
-        
-        
-        server.insert("isBuiltSystem".to_string(), json!(true));
-    } else {
-        utils::log_error("Server configuration section not found");
-        return Err(GoaError::Configuration("Server configuration section not found".to_string()).into());
+    utils::log_success("Meta information updated");
+    Ok(())
+}
+
+fn configure_cdn(cdn: &mut CdnConfig) -> Result<()> {
+    println!("\n{}", "CDN LIBRARIES".bold().underline());
+    
+    if utils::prompt_confirm("Reset CDN libraries to the framework defaults", false)? {
+        cdn.reset_to_defaults();
+        utils::log_success("CDN libraries reset to the framework defaults");
+        return Ok(());
     }
     
+    cdn.use_cdn = utils::prompt_confirm("Load libraries from the CDN", cdn.use_cdn)?;
     
-    let production_config = serde_json::to_string_pretty(&config)
-        .map_err(|e| GoaError::Json(e))?;
+    let assets: Vec<(&str, String)> = cdn.assets()
+        .into_iter()
+        .map(|(key, url)| (key, url.to_string()))
+        .collect();
     
-    fs::write(&temp_config_path, &production_config)
-        .map_err(|e| GoaError::Io(e))?;
+    for (key, current) in assets {
+        let url = utils::prompt_validated_input(format!("{} URL", key), Some(current.clone()), utils::validate_asset_url)?;
+        if url != current {
+            if cdn.integrity.remove(key).is_some() {
+                utils::log_info(&format!("Removed the integrity hash for {}; run `goa assets sri` to recompute it", key));
+            }
+            cdn.set_asset(key, url);
+        }
+    }
     
+    utils::log_success("CDN settings updated");
+    Ok(())
+}
+
+fn build_project(
+    output_dir: Option<String>,
+    base_url: Option<String>,
+    params: Vec<String>,
+    params_file: Option<String>,
+    env: &str,
+    css: bool,
+    no_css: bool,
+) -> Result<()> {
+    utils::log_step(&format!("Building Go on Airplanes project for {}", env));
     
-    let backup_config_path = project_dir.join("config.json.bak");
-    fs::copy(&config_path, &backup_config_path)
-        .map_err(|e| GoaError::Io(e))?;
     
+    let config_path = find_config_file()?;
     
-    fs::copy(&temp_config_path, &config_path)
-        .map_err(|e| GoaError::Io(e))?;
     
+    let project_dir = config_path.parent().unwrap().to_path_buf();
     
-    fs::remove_file(&temp_config_path).ok();
+    let config = LayeredConfig::load(&config_path, Some(env))?.config;
     
-    utils::log_success("Temporarily updated config for production build");
+    let route_params = pages::load_route_params(&params, params_file.as_deref())?;
+    
+    if !no_css && (css || crate::commands::assets::css_output_path(&project_dir, &config).exists()) {
+        crate::commands::assets::compile_css(&project_dir, &config)?;
+        crate::commands::assets::hint_unlinked_css(&project_dir, &config);
+    }
     
     
     let target_dir = match output_dir {
@@ -798,91 +793,72 @@ fn build_project(output_dir: Option<String>) -> Result<()> {
     
     if !target_dir.exists() {
         fs::create_dir_all(&target_dir)
-            .map_err(|e| GoaError::Io(e))?;
+            .map_err(GoaError::Io)?;
     }
     
     
     let main_go_path = project_dir.join("main.go");
     if !main_go_path.exists() {
-        utils::log_error("main.go not found in project directory");
-        
-        
-        fs::copy(&backup_config_path, &config_path)
-            .map_err(|e| GoaError::Io(e))?;
-        fs::remove_file(&backup_config_path).ok();
-        
         return Err(GoaError::ProjectCreation("main.go not found in project directory".to_string()).into());
     }
     
     
+    let config_override = ConfigOverride::apply(&config_path, &config)?;
+    utils::log_success(&format!("Temporarily applied {} config for the build", env));
+    
+    
     utils::log_step("Running build process...");
     
     let executable_name = if cfg!(windows) { "app.exe" } else { "app" };
     let output_path = target_dir.join(executable_name);
     
-    let build_result = Command::new("go")
+    let mut build = Command::new("go");
+    build
         .args([
             "build",
             "-o", 
             &output_path.to_string_lossy()
         ])
-        .current_dir(&project_dir)
-        .output();
-    
+        .current_dir(&project_dir);
+    utils::log_command(&build);
+    let build_result = build.output();
     
-    fs::copy(&backup_config_path, &config_path)
-        .map_err(|e| GoaError::Io(e))?;
-    fs::remove_file(&backup_config_path).ok();
     
-    utils::log_success("Restored original configuration");
+    drop(config_override);
     
     
     match build_result {
         Ok(output) => {
             if !output.status.success() {
                 let error = String::from_utf8_lossy(&output.stderr);
-                utils::log_error(&format!("Build failed: {}", error));
                 return Err(GoaError::ProjectCreation(format!("Build failed: {}", error)).into());
             }
             
             utils::log_success("Build completed successfully!");
             
             
-            let mut prod_config: Value = serde_json::from_str(&config_str)
-                .map_err(|e| GoaError::Json(e))?;
-            
+            config.save(target_dir.join("config.json"))?;
+                
+            utils::log_success(&format!("Saved {} config.json to build directory", env));
             
-            if !prod_config.as_object().unwrap().contains_key("server") {
-                prod_config["server"] = json!({});
+            if let Some(base_url) = base_url {
+                let count = sitemap::write_sitemap_files(&project_dir, &config, &base_url, &route_params, &target_dir, "/sitemap.xml")?;
+                utils::log_success(&format!("Wrote sitemap.xml with {} URL(s) and robots.txt to the build directory", count));
             }
             
             
-            if let Some(server) = prod_config.get_mut("server").and_then(|s| s.as_object_mut()) {
-                server.insert("devMode".to_string(), json!(false));
-                server.insert("liveReload".to_string(), json!(false)); 
-                server.insert("isBuiltSystem".to_string(), json!(true));
+            if logger::shows_decorations() {
+                println!("\n{}", "╭───────────────────────────────────────────────────╮".cyan());
+                println!("{}{:^53}{}", "│".cyan(), "BUILD COMPLETED SUCCESSFULLY".green().bold(), "│".cyan());
+                println!("{}", "╰───────────────────────────────────────────────────╯".cyan());
             }
             
-            let prod_config_str = serde_json::to_string_pretty(&prod_config)
-                .map_err(|e| GoaError::Json(e))?;
-                
-            fs::write(target_dir.join("config.json"), prod_config_str)
-                .map_err(|e| GoaError::Io(e))?;
-                
-            utils::log_success("Saved production config.json to build directory");
-            
-            
-            println!("\n{}", "╭───────────────────────────────────────────────────╮".cyan());
-            println!("{}{:^53}{}", "│".cyan(), "BUILD COMPLETED SUCCESSFULLY".green().bold(), "│".cyan());
-            println!("{}", "╰───────────────────────────────────────────────────╯".cyan());
-            
             utils::log_info(&format!("Build output: {}", target_dir.display()));
             utils::log_info(&format!("Executable: {}", output_path.display()));
             
             Ok(())
         },
         Err(e) => {
-            utils::log_error(&format!("Failed to run build: {}", e));
             Err(GoaError::ProjectCreation(format!("Failed to run build: {}", e)).into())
         }
     }
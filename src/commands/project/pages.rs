@@ -0,0 +1,234 @@
+use anyhow::Result;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::errors::GoaError;
+
+pub fn collect_page_routes(app_path: &Path) -> Result<Vec<String>> {
+    let files = super::find_routes_in_directory(app_path, "index.html", |path| {
+        !path.to_string_lossy().contains("/components/") &&
+        !path.to_string_lossy().contains("/api/")
+    })?;
+
+    let mut routes = BTreeSet::new();
+    for file in files {
+        routes.insert(route_for_page_file(app_path, &file));
+    }
+
+    Ok(routes.into_iter().collect())
+}
+
+fn route_for_page_file(app_path: &Path, file: &Path) -> String {
+    let relative_path = file.strip_prefix(app_path).unwrap_or(file);
+    let parent = relative_path.parent().unwrap_or(relative_path);
+
+    let parts: Vec<String> = parent
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().to_string())
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    if parts.is_empty() {
+        "/".to_string()
+    } else {
+        format!("/{}", parts.join("/"))
+    }
+}
+
+pub fn page_file_for_route(app_path: &Path, route: &str) -> PathBuf {
+    let mut path = app_path.to_path_buf();
+    for part in route.split('/').filter(|s| !s.is_empty()) {
+        path.push(part);
+    }
+    path.join("index.html")
+}
+
+pub fn is_dynamic_route(route: &str) -> bool {
+    route.split('/').any(|part| part.starts_with('[') && part.ends_with(']'))
+}
+
+pub fn load_route_params(params: &[String], params_file: Option<&str>) -> Result<BTreeMap<String, Vec<String>>> {
+    let mut result: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+    if let Some(file) = params_file {
+        let content = fs::read_to_string(file)
+            .map_err(|e| GoaError::InvalidPath(format!("Failed to read params file {}: {}", file, e)))?;
+
+        let parsed: BTreeMap<String, Vec<String>> = serde_json::from_str(&content)
+            .map_err(|e| GoaError::Configuration(format!(
+                "Params file {} must map routes to lists of values (e.g. {{\"/users/[id]\": [\"1\", \"2\"]}}): {}",
+                file, e
+            )))?;
+
+        for (route, values) in parsed {
+            result.entry(normalize_route(&route)).or_default().extend(values);
+        }
+    }
+
+    for param in params {
+        let (route, values) = param.split_once('=').ok_or_else(|| GoaError::Other(format!(
+            "Invalid param '{}'. Expected ROUTE=VALUE[,VALUE...], e.g. /users/[id]=1,2,3",
+            param
+        )))?;
+
+        result
+            .entry(normalize_route(route))
+            .or_default()
+            .extend(values.split(',').map(|v| v.trim().to_string()).filter(|v| !v.is_empty()));
+    }
+
+    for (route, values) in &result {
+        for value in values {
+            validate_param_value(route, value)?;
+        }
+    }
+
+    Ok(result)
+}
+
+fn validate_param_value(route: &str, value: &str) -> Result<()> {
+    let expected = route.split('/').filter(|part| part.starts_with('[') && part.ends_with(']')).count();
+    let segments: Vec<&str> = value.split('/').collect();
+
+    if segments.len() != expected {
+        return Err(GoaError::InvalidPath(format!(
+            "Param value '{}' for {} must have {} segment(s) separated by '/', one per [param]",
+            value, route, expected
+        )).into());
+    }
+
+    for segment in segments {
+        if !is_safe_segment(segment) {
+            return Err(GoaError::InvalidPath(format!(
+                "Param value '{}' for {} is not a valid path segment (empty, '.', '..' and '\\' are not allowed)",
+                value, route
+            )).into());
+        }
+    }
+
+    Ok(())
+}
+
+pub fn expand_route(route: &str, values: &[String]) -> Vec<String> {
+    values
+        .iter()
+        .filter_map(|value| {
+            let mut replacements = value.split('/');
+            let mut parts = Vec::new();
+
+            for part in route.split('/').filter(|s| !s.is_empty()) {
+                if part.starts_with('[') && part.ends_with(']') {
+                    let replacement = replacements.next()?;
+                    if !is_safe_segment(replacement) {
+                        return None;
+                    }
+                    parts.push(replacement.to_string());
+                } else {
+                    parts.push(part.to_string());
+                }
+            }
+
+            if replacements.next().is_some() {
+                return None;
+            }
+
+            Some(format!("/{}", parts.join("/")))
+        })
+        .collect()
+}
+
+pub fn encode_url_path(path: &str) -> String {
+    let mut encoded = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        if byte.is_ascii_alphanumeric() || b"/-._~".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
+fn is_safe_segment(segment: &str) -> bool {
+    !segment.is_empty() && segment != "." && segment != ".." && !segment.contains(['\\', '\0'])
+}
+
+fn normalize_route(route: &str) -> String {
+    let trimmed = route.trim().trim_matches('/');
+    if trimmed.is_empty() {
+        "/".to_string()
+    } else {
+        format!("/{}", trimmed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn load_route_params_merges_flags_and_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("params.json");
+        fs::write(&file, r#"{ "users/[id]/": ["1"], "/blog/[year]/[slug]": ["2024/hello"] }"#).unwrap();
+
+        let params = load_route_params(
+            &strings(&["/users/[id]=2, 3,", "/tags/[tag]=rust"]),
+            Some(file.to_str().unwrap()),
+        )
+        .unwrap();
+
+        assert_eq!(params["/users/[id]"], strings(&["1", "2", "3"]));
+        assert_eq!(params["/blog/[year]/[slug]"], strings(&["2024/hello"]));
+        assert_eq!(params["/tags/[tag]"], strings(&["rust"]));
+    }
+
+    #[test]
+    fn load_route_params_rejects_malformed_input() {
+        assert!(load_route_params(&strings(&["/users/[id]"]), None).is_err());
+        assert!(load_route_params(&strings(&["/users/[id]=.."]), None).is_err());
+        assert!(load_route_params(&[], Some("/nonexistent/params.json")).is_err());
+
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("params.json");
+        fs::write(&file, r#"{ "/users/[id]": "1" }"#).unwrap();
+        assert!(load_route_params(&[], Some(file.to_str().unwrap())).is_err());
+    }
+
+    #[test]
+    fn validate_param_value_checks_segments() {
+        assert!(validate_param_value("/users/[id]", "42").is_ok());
+        assert!(validate_param_value("/blog/[year]/[slug]", "2024/hello").is_ok());
+        assert!(validate_param_value("/blog/[year]/[slug]", "2024").is_err());
+        assert!(validate_param_value("/users/[id]", "a/b").is_err());
+        assert!(validate_param_value("/users/[id]", "..").is_err());
+        assert!(validate_param_value("/users/[id]", ".").is_err());
+        assert!(validate_param_value("/users/[id]", "").is_err());
+        assert!(validate_param_value("/users/[id]", "a\\b").is_err());
+    }
+
+    #[test]
+    fn expand_route_fills_each_segment() {
+        assert_eq!(
+            expand_route("/users/[id]/posts", &strings(&["1", "two"])),
+            strings(&["/users/1/posts", "/users/two/posts"])
+        );
+        assert_eq!(
+            expand_route("/blog/[year]/[slug]", &strings(&["2024/hello", "2024", "2024/a/b", "../x"])),
+            strings(&["/blog/2024/hello"])
+        );
+    }
+
+    #[test]
+    fn encode_url_path_escapes_reserved_characters() {
+        assert_eq!(encode_url_path("/users/a b&c"), "/users/a%20b%26c");
+        assert_eq!(encode_url_path("/tags/c++/ü"), "/tags/c%2B%2B/%C3%BC");
+        assert_eq!(encode_url_path("/plain-path_1.0~x"), "/plain-path_1.0~x");
+        assert_eq!(encode_url_path("/q?x=1#y"), "/q%3Fx%3D1%23y");
+    }
+}
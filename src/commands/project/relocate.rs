@@ -0,0 +1,395 @@
+use anyhow::Result;
+use fs_extra::dir::CopyOptions;
+use colored::Colorize;
+use regex::{Captures, Regex};
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+use crate::config::GoaConfig;
+use crate::errors::GoaError;
+use crate::utils;
+use crate::utils::logger;
+
+pub const MOVABLE_DIRECTORIES: [&str; 2] = ["directories.appDir", "directories.componentDir"];
+
+const SKIPPED_DIRECTORIES: [&str; 3] = ["node_modules", "vendor", "build"];
+
+pub fn relocate_directory<F>(
+    project_dir: &Path,
+    config: &GoaConfig,
+    key: &str,
+    new_dir: &str,
+    assume_yes: bool,
+    save: F,
+) -> Result<Vec<(&'static str, String)>>
+where
+    F: FnOnce(&[(&'static str, String)]) -> Result<()>,
+{
+    let paths = path_settings(config);
+    let old_dir = paths
+        .iter()
+        .find(|(setting, _)| *setting == key)
+        .map(|(_, value)| normalize(value))
+        .ok_or_else(|| GoaError::Configuration(format!("`{}` is not a movable directory", key)))?;
+    let new_dir = normalize(new_dir);
+
+    if old_dir == new_dir {
+        return Ok(Vec::new());
+    }
+
+    let old_path = project_dir.join(&old_dir);
+    let new_path = project_dir.join(&new_dir);
+
+    if is_inside(&new_dir, &old_dir) {
+        return Err(GoaError::InvalidPath(format!("Cannot move {} into itself ({})", old_dir, new_dir)).into());
+    }
+
+    if new_path.exists() && (!new_path.is_dir() || fs::read_dir(&new_path).map_err(GoaError::Io)?.next().is_some()) {
+        return Err(GoaError::InvalidPath(format!(
+            "{} already exists and is not empty; move or remove it first",
+            new_dir
+        )).into());
+    }
+
+    let mut rewrites = plan_rewrites(project_dir, &old_dir, &new_dir)?;
+    if !rewrites.is_empty() && !confirm_rewrites(project_dir, &old_dir, &rewrites, assume_yes)? {
+        utils::log_warning(&format!("References were left unchanged; update them to {} by hand", new_dir));
+        rewrites.clear();
+    }
+
+    let mut changes = Vec::new();
+    for (setting, value) in paths {
+        let updated = if setting == key {
+            Some(new_dir.clone())
+        } else {
+            rebase(&normalize(&value), &old_dir, &new_dir)
+        };
+
+        if let Some(updated) = updated {
+            changes.push((setting, updated));
+        }
+    }
+
+    utils::log_step(&format!("Moving {} to {}", old_dir, new_dir));
+    let moved = move_tree(&old_path, &new_path)?;
+
+    let mut written = Vec::new();
+    let result = write_rewrites(&rewrites, &old_path, &new_path, &mut written).and_then(|()| save(&changes));
+    if let Err(error) = result {
+        utils::log_warning(&format!("Relocation failed, moving {} back to {}", new_dir, old_dir));
+        for (path, original) in written.iter().rev() {
+            fs::write(path, original).ok();
+        }
+        if moved {
+            move_tree(&new_path, &old_path)?;
+        }
+        return Err(error);
+    }
+
+    for (setting, updated) in &changes {
+        if *setting != key {
+            utils::log_info(&format!("Updated {} to {}", setting, updated));
+        }
+    }
+    if !rewrites.is_empty() {
+        utils::log_success(&format!("Updated references in {} file(s)", rewrites.len()));
+    }
+
+    utils::log_success(&format!("Moved {} to {}", old_dir, new_dir));
+    Ok(changes)
+}
+
+pub fn apply_path_changes(config: &mut GoaConfig, changes: &[(&'static str, String)]) {
+    for (setting, value) in changes {
+        let value = value.clone();
+        match *setting {
+            "directories.appDir" => config.directories.app_dir = value,
+            "directories.staticDir" => config.directories.static_dir = value,
+            "directories.layoutPath" => config.directories.layout_path = value,
+            "directories.componentDir" => config.directories.component_dir = value,
+            "ssg.directory" => config.ssg.directory = value,
+            _ => {}
+        }
+    }
+}
+
+fn path_settings(config: &GoaConfig) -> Vec<(&'static str, String)> {
+    vec![
+        ("directories.appDir", config.directories.app_dir.clone()),
+        ("directories.staticDir", config.directories.static_dir.clone()),
+        ("directories.layoutPath", config.directories.layout_path.clone()),
+        ("directories.componentDir", config.directories.component_dir.clone()),
+        ("ssg.directory", config.ssg.directory.clone()),
+    ]
+}
+
+fn normalize(path: &str) -> String {
+    path.trim().trim_start_matches("./").trim_end_matches('/').to_string()
+}
+
+fn is_inside(path: &str, dir: &str) -> bool {
+    path.strip_prefix(dir).map(|rest| rest.starts_with('/')).unwrap_or(false)
+}
+
+fn rebase(path: &str, old_dir: &str, new_dir: &str) -> Option<String> {
+    if is_inside(path, old_dir) {
+        Some(format!("{}{}", new_dir, &path[old_dir.len()..]))
+    } else {
+        None
+    }
+}
+
+fn move_tree(from: &Path, to: &Path) -> Result<bool> {
+    if !from.exists() {
+        utils::log_info(&format!("{} does not exist yet, nothing to move", from.display()));
+        return Ok(false);
+    }
+
+    if let Some(parent) = to.parent() {
+        fs::create_dir_all(parent).map_err(GoaError::Io)?;
+    }
+    if to.is_dir() {
+        fs::remove_dir(to).map_err(GoaError::Io)?;
+    }
+
+    if fs::rename(from, to).is_err() {
+        let options = CopyOptions {
+            content_only: true,
+            ..CopyOptions::new()
+        };
+        fs::create_dir_all(to).map_err(GoaError::Io)?;
+        fs_extra::dir::move_dir(from, to, &options)
+            .map_err(|e| GoaError::Other(format!("Failed to move {}: {}", from.display(), e)))?;
+    }
+
+    Ok(true)
+}
+
+struct FileRewrite {
+    path: PathBuf,
+    content: String,
+    changes: Vec<(usize, String, String)>,
+}
+
+fn confirm_rewrites(project_dir: &Path, old_dir: &str, rewrites: &[FileRewrite], assume_yes: bool) -> Result<bool> {
+    let count: usize = rewrites.iter().map(|rewrite| rewrite.changes.len()).sum();
+    utils::log_info(&format!("Found {} reference(s) to {} that point into the moved files:", count, old_dir));
+    for rewrite in rewrites {
+        let display = rewrite.path.strip_prefix(project_dir).unwrap_or(&rewrite.path);
+        for (line, before, after) in &rewrite.changes {
+            if logger::is_json() {
+                utils::log_info(&format!("{}:{}  {} -> {}", display.display(), line, before, after));
+            } else {
+                println!("  {}:{}  {} {} {}", display.display(), line, before.red(), "->".dimmed(), after.green());
+            }
+        }
+    }
+
+    if assume_yes || !console::user_attended() {
+        return Ok(true);
+    }
+    Ok(utils::prompt_confirm("Rewrite these references", true)?)
+}
+
+fn write_rewrites(
+    rewrites: &[FileRewrite],
+    old_path: &Path,
+    new_path: &Path,
+    written: &mut Vec<(PathBuf, String)>,
+) -> Result<()> {
+    for rewrite in rewrites {
+        let path = match rewrite.path.strip_prefix(old_path) {
+            Ok(relative) => new_path.join(relative),
+            Err(_) => rewrite.path.clone(),
+        };
+        let original = fs::read_to_string(&path).map_err(GoaError::Io)?;
+        fs::write(&path, &rewrite.content).map_err(GoaError::Io)?;
+        written.push((path, original));
+    }
+    Ok(())
+}
+
+fn plan_rewrites(project_dir: &Path, old_dir: &str, new_dir: &str) -> Result<Vec<FileRewrite>> {
+    let go_import = if project_dir.join("go.mod").is_file() {
+        let module = utils::go_module_name(project_dir);
+        Some((
+            Regex::new(&format!(r#""{}/{}((?:/[^"]*)?)""#, regex::escape(&module), regex::escape(old_dir))).unwrap(),
+            module,
+        ))
+    } else {
+        None
+    };
+    let file_path = Regex::new(&format!(r#"(["'`])(\./)?{}/([^"'`\s{{}}]+)(["'`])"#, regex::escape(old_dir))).unwrap();
+    let old_root = project_dir.join(old_dir);
+
+    let entries = WalkDir::new(project_dir)
+        .into_iter()
+        .filter_entry(|entry| {
+            let name = entry.file_name().to_string_lossy();
+            entry.depth() == 0 || !(name.starts_with('.') || SKIPPED_DIRECTORIES.contains(&name.as_ref()))
+        })
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file());
+
+    let mut rewrites = Vec::new();
+    for entry in entries {
+        let is_go = match entry.path().extension().and_then(|e| e.to_str()) {
+            Some("go") => true,
+            Some("html") => false,
+            _ => continue,
+        };
+
+        let original = match fs::read_to_string(entry.path()) {
+            Ok(content) => content,
+            Err(_) => continue,
+        };
+
+        let mut changes = Vec::new();
+        let mut content = original.clone();
+
+        if let (true, Some((regex, module))) = (is_go, &go_import) {
+            content = replace_recorded(&content, regex, &mut changes, |captures| {
+                Some(format!(r#""{}/{}{}""#, module, new_dir, &captures[1]))
+            });
+        }
+
+        content = replace_recorded(&content, &file_path, &mut changes, |captures| {
+            let rest = &captures[3];
+            if captures[1] != captures[4] || !old_root.join(rest).is_file() {
+                return None;
+            }
+            let prefix = captures.get(2).map(|m| m.as_str()).unwrap_or("");
+            Some(format!("{}{}{}/{}{}", &captures[1], prefix, new_dir, rest, &captures[4]))
+        });
+
+        if content != original {
+            rewrites.push(FileRewrite {
+                path: entry.into_path(),
+                content,
+                changes,
+            });
+        }
+    }
+
+    Ok(rewrites)
+}
+
+fn replace_recorded<F>(content: &str, regex: &Regex, changes: &mut Vec<(usize, String, String)>, replacement: F) -> String
+where
+    F: Fn(&Captures) -> Option<String>,
+{
+    let mut output = String::with_capacity(content.len());
+    let mut last = 0;
+
+    for captures in regex.captures_iter(content) {
+        let whole = captures.get(0).unwrap();
+        if let Some(updated) = replacement(&captures) {
+            let line = content[..whole.start()].matches('\n').count() + 1;
+            changes.push((line, whole.as_str().to_string(), updated.clone()));
+            output.push_str(&content[last..whole.start()]);
+            output.push_str(&updated);
+            last = whole.end();
+        }
+    }
+
+    output.push_str(&content[last..]);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        utils::write_file(root.join("go.mod"), "module example.com/site\n\ngo 1.21\n").unwrap();
+        utils::write_file(root.join("main.go"), "package main\n\nimport (\n\t_ \"example.com/site/app/api/users\"\n\t_ \"example.com/site/application\"\n)\n").unwrap();
+        utils::write_file(root.join("app/api/users/route.go"), "package users\n").unwrap();
+        utils::write_file(root.join("app/x.js"), "").unwrap();
+        utils::write_file(
+            root.join("app/index.html"),
+            "<script src=\"./app/x.js\"></script>\n<div class=\"app\" id='app'></div>\n<a href=\"/app/settings\">{{template \"app\"}}</a>\n<img src=\"app/missing.png\">\n",
+        )
+        .unwrap();
+        dir
+    }
+
+    #[test]
+    fn is_inside_requires_a_path_separator() {
+        assert!(is_inside("app/components", "app"));
+        assert!(!is_inside("app", "app"));
+        assert!(!is_inside("application", "app"));
+        assert!(!is_inside("src/app", "app"));
+    }
+
+    #[test]
+    fn rebase_moves_nested_paths_only() {
+        assert_eq!(rebase("app/layout.html", "app", "src"), Some("src/layout.html".to_string()));
+        assert_eq!(rebase("app/components", "app", "src/app"), Some("src/app/components".to_string()));
+        assert_eq!(rebase("static", "app", "src"), None);
+        assert_eq!(rebase("apps/x", "app", "src"), None);
+    }
+
+    #[test]
+    fn plan_rewrites_only_touches_imports_and_existing_files() {
+        let dir = project();
+
+        let rewrites = plan_rewrites(dir.path(), "app", "src").unwrap();
+
+        assert_eq!(rewrites.len(), 2);
+        let html = rewrites.iter().find(|r| r.path.ends_with("index.html")).unwrap();
+        assert_eq!(html.changes, vec![(1, "\"./app/x.js\"".to_string(), "\"./src/x.js\"".to_string())]);
+        assert!(html.content.contains("class=\"app\" id='app'"));
+        assert!(html.content.contains("href=\"/app/settings\""));
+        assert!(html.content.contains("src=\"app/missing.png\""));
+
+        let main = rewrites.iter().find(|r| r.path.ends_with("main.go")).unwrap();
+        assert_eq!(main.changes.len(), 1);
+        assert!(main.content.contains("\"example.com/site/src/api/users\""));
+        assert!(main.content.contains("\"example.com/site/application\""));
+    }
+
+    #[test]
+    fn relocate_moves_files_and_rewrites_references() {
+        let dir = project();
+        let config = GoaConfig::default();
+
+        let changes = relocate_directory(dir.path(), &config, "directories.appDir", "src", true, |_| Ok(())).unwrap();
+
+        assert!(changes.contains(&("directories.appDir", "src".to_string())));
+        assert!(changes.contains(&("directories.componentDir", "src/components".to_string())));
+        assert!(!dir.path().join("app").exists());
+        assert!(fs::read_to_string(dir.path().join("src/index.html")).unwrap().contains("./src/x.js"));
+        assert!(fs::read_to_string(dir.path().join("main.go")).unwrap().contains("example.com/site/src/api/users"));
+    }
+
+    #[test]
+    fn relocate_puts_the_tree_back_when_saving_fails() {
+        let dir = project();
+        let config = GoaConfig::default();
+        let main_before = fs::read_to_string(dir.path().join("main.go")).unwrap();
+        let index_before = fs::read_to_string(dir.path().join("app/index.html")).unwrap();
+
+        let result = relocate_directory(dir.path(), &config, "directories.appDir", "src", true, |_| {
+            Err(GoaError::Other("disk full".to_string()).into())
+        });
+
+        assert!(result.is_err());
+        assert!(!dir.path().join("src").exists());
+        assert_eq!(fs::read_to_string(dir.path().join("main.go")).unwrap(), main_before);
+        assert_eq!(fs::read_to_string(dir.path().join("app/index.html")).unwrap(), index_before);
+    }
+
+    #[test]
+    fn relocate_refuses_to_move_into_itself() {
+        let dir = project();
+        let config = GoaConfig::default();
+
+        let result = relocate_directory(dir.path(), &config, "directories.appDir", "app/nested", true, |_| Ok(()));
+
+        assert!(result.is_err());
+        assert!(dir.path().join("app/index.html").exists());
+    }
+}
@@ -0,0 +1,202 @@
+use anyhow::Result;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::pages;
+use crate::config::{find_config_file, GoaConfig};
+use crate::errors::GoaError;
+use crate::utils;
+
+const EXCLUDE_MARKER: &str = "goa:sitemap-exclude";
+
+pub fn generate_sitemap(base_url: String, params: Vec<String>, params_file: Option<String>) -> Result<()> {
+    utils::log_step("Generating sitemap.xml and robots.txt");
+
+    let config_path = find_config_file()?;
+    let config = GoaConfig::load(&config_path)?;
+    let project_dir = config_path.parent().unwrap().to_path_buf();
+
+    let route_params = pages::load_route_params(&params, params_file.as_deref())?;
+    let static_path = project_dir.join(&config.directories.static_dir);
+    let sitemap_path = format!("{}/sitemap.xml", config.get_static_url_prefix());
+    let count = write_sitemap_files(&project_dir, &config, &base_url, &route_params, &static_path, &sitemap_path)?;
+
+    utils::log_success(&format!("Sitemap generated with {} URL(s)", count));
+    Ok(())
+}
+
+pub fn write_sitemap_files(
+    project_dir: &Path,
+    config: &GoaConfig,
+    base_url: &str,
+    route_params: &BTreeMap<String, Vec<String>>,
+    output_dir: &Path,
+    sitemap_path: &str,
+) -> Result<usize> {
+    let base_url = normalize_base_url(base_url)?;
+
+    let app_path = project_dir.join(&config.directories.app_dir);
+    if !app_path.exists() {
+        return Err(GoaError::InvalidPath(format!("App directory not found at {}", app_path.display())).into());
+    }
+
+    let mut entries = Vec::new();
+    for route in pages::collect_page_routes(&app_path)? {
+        let page_file = pages::page_file_for_route(&app_path, &route);
+        let content = fs::read_to_string(&page_file).map_err(GoaError::Io)?;
+
+        if content.contains(EXCLUDE_MARKER) {
+            utils::log_info(&format!("Excluding {} from sitemap", route));
+            continue;
+        }
+
+        let lastmod = fs::metadata(&page_file)
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(format_date);
+
+        if !pages::is_dynamic_route(&route) {
+            entries.push((route, lastmod));
+            continue;
+        }
+
+        match route_params.get(&route) {
+            Some(values) => {
+                for url in pages::expand_route(&route, values) {
+                    entries.push((url, lastmod.clone()));
+                }
+            }
+            None => utils::log_warning(&format!(
+                "Skipping dynamic route {} (no values given; pass --param \"{}=...\" or --params-file)",
+                route, route
+            )),
+        }
+    }
+
+    let mut sitemap = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    sitemap.push_str("<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n");
+    for (route, lastmod) in &entries {
+        sitemap.push_str("  <url>\n");
+        sitemap.push_str(&format!("    <loc>{}</loc>\n", escape_xml(&format!("{}{}", base_url, pages::encode_url_path(route)))));
+        if let Some(lastmod) = lastmod {
+            sitemap.push_str(&format!("    <lastmod>{}</lastmod>\n", lastmod));
+        }
+        sitemap.push_str("  </url>\n");
+    }
+    sitemap.push_str("</urlset>\n");
+
+    let robots = format!("User-agent: *\nAllow: /\n\nSitemap: {}{}\n", base_url, sitemap_path);
+
+    utils::write_file(output_dir.join("sitemap.xml"), &sitemap)?;
+    utils::write_file(output_dir.join("robots.txt"), &robots)?;
+
+    Ok(entries.len())
+}
+
+fn normalize_base_url(base_url: &str) -> Result<String> {
+    let base_url = base_url.trim().trim_end_matches('/');
+
+    if !(base_url.starts_with("http://") || base_url.starts_with("https://")) || base_url.contains(char::is_whitespace) {
+        return Err(GoaError::Other(format!(
+            "Invalid base URL '{}'. Expected an absolute URL such as https://example.com",
+            base_url
+        )).into());
+    }
+
+    Ok(base_url.to_string())
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn format_date(time: SystemTime) -> Option<String> {
+    let days = time.duration_since(UNIX_EPOCH).ok()?.as_secs() as i64 / 86_400;
+
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    Some(format!("{:04}-{:02}-{:02}", year, month, day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn date(secs: u64) -> String {
+        format_date(UNIX_EPOCH + Duration::from_secs(secs)).unwrap()
+    }
+
+    #[test]
+    fn formats_the_epoch() {
+        assert_eq!(date(0), "1970-01-01");
+        assert_eq!(date(86_399), "1970-01-01");
+        assert_eq!(date(86_400), "1970-01-02");
+    }
+
+    #[test]
+    fn formats_leap_days() {
+        assert_eq!(date(951_782_400), "2000-02-29");
+        assert_eq!(date(951_868_800), "2000-03-01");
+        assert_eq!(date(1_709_164_800), "2024-02-29");
+        assert_eq!(date(1_709_251_200), "2024-03-01");
+    }
+
+    #[test]
+    fn skips_the_leap_day_in_2100() {
+        assert_eq!(date(4_107_456_000), "2100-02-28");
+        assert_eq!(date(4_107_542_400), "2100-03-01");
+        assert_eq!(date(4_133_894_400), "2100-12-31");
+    }
+
+    #[test]
+    fn rejects_relative_base_urls() {
+        assert_eq!(normalize_base_url(" https://example.com/ ").unwrap(), "https://example.com");
+        assert!(normalize_base_url("example.com").is_err());
+        assert!(normalize_base_url("https://exa mple.com").is_err());
+    }
+
+    #[test]
+    fn writes_sitemap_and_robots_into_the_output_directory() {
+        let project = tempfile::tempdir().unwrap();
+        let output = tempfile::tempdir().unwrap();
+        let config = GoaConfig::default();
+        let app = project.path().join(&config.directories.app_dir);
+        for page in ["index.html", "about/index.html", "users/[id]/index.html", "drafts/index.html"] {
+            utils::write_file(app.join(page), "<h1>Page</h1>").unwrap();
+        }
+        fs::write(app.join("drafts/index.html"), "<!-- goa:sitemap-exclude -->").unwrap();
+
+        let mut params = BTreeMap::new();
+        params.insert("/users/[id]".to_string(), vec!["1".to_string(), "a&b c".to_string()]);
+        let count = write_sitemap_files(project.path(), &config, "https://example.com/", &params, output.path(), "/sitemap.xml").unwrap();
+
+        assert_eq!(count, 4);
+        let sitemap = fs::read_to_string(output.path().join("sitemap.xml")).unwrap();
+        assert!(sitemap.contains("<loc>https://example.com/</loc>"));
+        assert!(sitemap.contains("<loc>https://example.com/about</loc>"));
+        assert!(sitemap.contains("<loc>https://example.com/users/1</loc>"));
+        assert!(sitemap.contains("<loc>https://example.com/users/a%26b%20c</loc>"));
+        assert!(!sitemap.contains("drafts"));
+        assert_eq!(
+            fs::read_to_string(output.path().join("robots.txt")).unwrap(),
+            "User-agent: *\nAllow: /\n\nSitemap: https://example.com/sitemap.xml\n"
+        );
+        assert!(!project.path().join(&config.directories.static_dir).exists());
+    }
+}
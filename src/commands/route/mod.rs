@@ -1,10 +1,11 @@
 use anyhow::Result;
 use clap::Subcommand;
+use clap_complete::ArgValueCandidates;
 use std::fs;
-use std::path::PathBuf;
 
-use crate::config::GoaConfig;
-use crate::errors::{GoaError, GoaResult};
+use crate::commands::completions;
+use crate::config::{find_config_file, GoaConfig};
+use crate::errors::GoaError;
 use crate::templates;
 use crate::utils;
 
@@ -33,7 +34,7 @@ pub enum ApiCommands {
     
     
     Delete {
-        
+        #[clap(add = ArgValueCandidates::new(completions::api_route_candidates))]
         path: Option<String>,
     },
 }
@@ -48,7 +49,7 @@ pub enum PageCommands {
     
     
     Delete {
-        
+        #[clap(add = ArgValueCandidates::new(completions::page_route_candidates))]
         path: Option<String>,
     },
 }
@@ -77,7 +78,6 @@ fn create_api_route(path_option: Option<String>) -> Result<()> {
     
     
     if let Err(e) = utils::validate_route_path(&route_path) {
-        utils::log_error(&e);
         return Err(GoaError::RouteGeneration(e).into());
     }
     
@@ -107,8 +107,7 @@ fn create_api_route(path_option: Option<String>) -> Result<()> {
             &format!("Route file already exists at {}. Overwrite?", route_file_path.display()),
             false,
         )? {
-            utils::log_info("Route creation cancelled");
-            return Ok(());
+            return Err(GoaError::Cancelled("Route creation cancelled".to_string()).into());
         }
     }
     
@@ -116,8 +115,9 @@ fn create_api_route(path_option: Option<String>) -> Result<()> {
     utils::write_file(&route_file_path, &templates::api::route(&package_name))?;
     
     
-    let main_path = config_path.parent().unwrap().join("main.go");
-    utils::update_main_imports(&main_path, &route_path)?;
+    let project_dir = config_path.parent().unwrap();
+    let import_path = utils::api_import_path(project_dir, &config.directories.app_dir, &route_path);
+    utils::update_main_imports(&project_dir.join("main.go"), &import_path)?;
     
     utils::log_success(&format!("API route '{route_path}' created successfully!"));
     Ok(())
@@ -134,7 +134,6 @@ fn delete_api_route(path_option: Option<String>) -> Result<()> {
     
     
     if let Err(e) = utils::validate_route_path(&route_path) {
-        utils::log_error(&e);
         return Err(GoaError::RouteGeneration(e).into());
     }
     
@@ -152,7 +151,6 @@ fn delete_api_route(path_option: Option<String>) -> Result<()> {
     
     
     if !api_route_dir.exists() {
-        utils::log_error(&format!("API route '{}' does not exist", route_path));
         return Err(GoaError::RouteGeneration(format!("API route '{}' does not exist", route_path)).into());
     }
     
@@ -161,17 +159,18 @@ fn delete_api_route(path_option: Option<String>) -> Result<()> {
         &format!("Are you sure you want to delete the API route '{}'?", route_path),
         false,
     )? {
-        utils::log_info("Route deletion cancelled");
-        return Ok(());
+        return Err(GoaError::Cancelled("Route deletion cancelled".to_string()).into());
     }
     
     
     fs::remove_dir_all(&api_route_dir)
         .map_err(|e| GoaError::Io(e))?;
+    utils::log_file_deleted(&api_route_dir, "directory");
     
     
-    let main_path = config_path.parent().unwrap().join("main.go");
-    utils::remove_main_import(&main_path, &route_path)?;
+    let project_dir = config_path.parent().unwrap();
+    let import_path = utils::api_import_path(project_dir, &config.directories.app_dir, &route_path);
+    utils::remove_main_import(&project_dir.join("main.go"), &import_path)?;
     
     utils::log_success(&format!("API route '{route_path}' deleted successfully!"));
     Ok(())
@@ -188,7 +187,6 @@ fn create_page_route(path_option: Option<String>) -> Result<()> {
     
     
     if let Err(e) = utils::validate_route_path(&route_path) {
-        utils::log_error(&e);
         return Err(GoaError::RouteGeneration(e).into());
     }
     
@@ -221,8 +219,7 @@ fn create_page_route(path_option: Option<String>) -> Result<()> {
             &format!("Page file already exists at {}. Overwrite?", page_file_path.display()),
             false,
         )? {
-            utils::log_info("Page creation cancelled");
-            return Ok(());
+            return Err(GoaError::Cancelled("Page creation cancelled".to_string()).into());
         }
     }
     
@@ -247,7 +244,6 @@ fn delete_page_route(path_option: Option<String>) -> Result<()> {
     
     
     if let Err(e) = utils::validate_route_path(&route_path) {
-        utils::log_error(&e);
         return Err(GoaError::RouteGeneration(e).into());
     }
     
@@ -265,7 +261,6 @@ fn delete_page_route(path_option: Option<String>) -> Result<()> {
     
     
     if !page_dir.exists() {
-        utils::log_error(&format!("Page route '{}' does not exist", route_path));
         return Err(GoaError::RouteGeneration(format!("Page route '{}' does not exist", route_path)).into());
     }
     
@@ -274,37 +269,14 @@ fn delete_page_route(path_option: Option<String>) -> Result<()> {
         &format!("Are you sure you want to delete the page route '{}'?", route_path),
         false,
     )? {
-        utils::log_info("Page route deletion cancelled");
-        return Ok(());
+        return Err(GoaError::Cancelled("Page route deletion cancelled".to_string()).into());
     }
     
     
     fs::remove_dir_all(&page_dir)
         .map_err(|e| GoaError::Io(e))?;
+    utils::log_file_deleted(&page_dir, "directory");
     
     utils::log_success(&format!("Page route '{route_path}' deleted successfully!"));
     Ok(())
 }
-
-fn find_config_file() -> GoaResult<PathBuf> {
-    
-    let current_dir = std::env::current_dir().map_err(|e| GoaError::Io(e))?;
-    let config_path = current_dir.join("config.json");
-    
-    if config_path.exists() {
-        return Ok(config_path);
-    }
-    
-    
-    let mut dir = current_dir;
-    while let Some(parent) = dir.parent() {
-        let parent_config = parent.join("config.json");
-        if parent_config.exists() {
-            return Ok(parent_config);
-        }
-        dir = parent.to_path_buf();
-    }
-    
-    
-    Err(GoaError::Configuration("Could not find config.json file. Are you inside a Go on Airplanes project?".to_string()))
-} 
\ No newline at end of file
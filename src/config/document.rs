@@ -0,0 +1,517 @@
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::{spans, value_at_key, GoaConfig};
+use crate::errors::{GoaError, GoaResult};
+
+const FREE_FORM_MAPS: [&str; 2] = ["meta.defaultMetaTags", "cdn.integrity"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigPath {
+    segments: Vec<PathSegment>,
+}
+
+impl ConfigPath {
+    pub fn parse(path: &str) -> GoaResult<Self> {
+        let invalid = |reason: &str| GoaError::Configuration(format!("Invalid config path `{}`: {}", path, reason));
+
+        if path.trim().is_empty() {
+            return Err(invalid("path is empty"));
+        }
+
+        let mut segments = Vec::new();
+        for part in path.split('.') {
+            let (name, indexes) = match part.find('[') {
+                Some(index) => (&part[..index], &part[index..]),
+                None => (part, ""),
+            };
+
+            if name.is_empty() && (segments.is_empty() || indexes.is_empty()) {
+                return Err(invalid("empty key"));
+            }
+            if !name.is_empty() {
+                segments.push(PathSegment::Key(name.to_string()));
+            }
+
+            let mut rest = indexes;
+            while !rest.is_empty() {
+                let close = rest.find(']').ok_or_else(|| invalid("missing `]`"))?;
+                if !rest.starts_with('[') {
+                    return Err(invalid("unexpected characters after `]`"));
+                }
+                let index = rest[1..close].parse::<usize>()
+                    .map_err(|_| invalid("array indexes must be non-negative numbers"))?;
+                segments.push(PathSegment::Index(index));
+                rest = &rest[close + 1..];
+            }
+        }
+
+        Ok(Self { segments })
+    }
+
+    pub(super) fn root() -> Self {
+        Self { segments: Vec::new() }
+    }
+
+    pub(super) fn child(&self, key: &str) -> Self {
+        let mut segments = self.segments.clone();
+        segments.push(PathSegment::Key(key.to_string()));
+        Self { segments }
+    }
+
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.segments
+    }
+
+    fn parent(&self) -> Option<(ConfigPath, &PathSegment)> {
+        let (last, parent) = self.segments.split_last()?;
+        Some((ConfigPath { segments: parent.to_vec() }, last))
+    }
+
+    fn keys_only(&self) -> String {
+        self.segments
+            .iter()
+            .filter_map(|segment| match segment {
+                PathSegment::Key(key) => Some(key.as_str()),
+                PathSegment::Index(_) => None,
+            })
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    pub fn lookup<'a>(&self, value: &'a Value) -> Option<&'a Value> {
+        self.segments.iter().try_fold(value, |current, segment| match segment {
+            PathSegment::Key(key) => current.get(key),
+            PathSegment::Index(index) => current.get(index),
+        })
+    }
+
+    fn lookup_mut<'a>(&self, value: &'a mut Value) -> Option<&'a mut Value> {
+        self.segments.iter().try_fold(value, |current, segment| match segment {
+            PathSegment::Key(key) => current.get_mut(key),
+            PathSegment::Index(index) => current.get_mut(index),
+        })
+    }
+}
+
+impl fmt::Display for ConfigPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.segments.iter().enumerate() {
+            match segment {
+                PathSegment::Key(key) if i == 0 => write!(f, "{}", key)?,
+                PathSegment::Key(key) => write!(f, ".{}", key)?,
+                PathSegment::Index(index) => write!(f, "[{}]", index)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct ConfigDocument {
+    path: PathBuf,
+    source: String,
+    value: Value,
+    indent: String,
+}
+
+impl ConfigDocument {
+    pub fn load(path: impl AsRef<Path>) -> GoaResult<Self> {
+        let path = path.as_ref();
+        let source = fs::read_to_string(path)
+            .map_err(|e| GoaError::Configuration(format!("Failed to read config file: {}", e)))?;
+
+        GoaConfig::parse(&source, path)?;
+        Self::from_source(path, source)
+    }
+
+    pub(super) fn from_source(path: &Path, source: String) -> GoaResult<Self> {
+        let value: Value = serde_json::from_str(&source)
+            .map_err(GoaError::Json)?;
+
+        if !value.is_object() {
+            return Err(GoaError::Configuration(format!("{} must contain a JSON object", path.display())));
+        }
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            value,
+            indent: detect_indent(&source),
+            source,
+        })
+    }
+
+    pub fn config(&self) -> GoaResult<GoaConfig> {
+        let source = serde_json::to_string_pretty(&self.value)
+            .map_err(GoaError::Json)?;
+        GoaConfig::parse(&source, &self.path)
+    }
+
+    pub(super) fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub(super) fn get(&self, path: &ConfigPath) -> Option<&Value> {
+        path.lookup(&self.value)
+    }
+
+    pub fn set(&mut self, path: &ConfigPath, raw: &str) -> GoaResult<Value> {
+        let new_value = coerce_value(path, raw, &self.expected_value(path)?)?;
+        self.validated_edit(|document| document.put(path, new_value.clone()))?;
+        Ok(new_value)
+    }
+
+    pub fn unset(&mut self, path: &ConfigPath) -> GoaResult<Value> {
+        self.validated_edit(|document| document.remove(path))
+    }
+
+    fn validated_edit<T>(&mut self, edit: impl FnOnce(&mut Self) -> GoaResult<T>) -> GoaResult<T> {
+        let (source, value) = (self.source.clone(), self.value.clone());
+        let result = edit(self).and_then(|output| {
+            self.config()?.validate()?;
+            Ok(output)
+        });
+
+        if result.is_err() {
+            self.source = source;
+            self.value = value;
+        }
+        result
+    }
+
+    pub(super) fn put(&mut self, path: &ConfigPath, new_value: Value) -> GoaResult<()> {
+        let (parent_path, last) = path.parent()
+            .ok_or_else(|| GoaError::Configuration("Cannot replace the whole configuration".to_string()))?;
+
+        self.create_parents(&parent_path)?;
+        let parent = parent_path.lookup_mut(&mut self.value)
+            .ok_or_else(|| GoaError::Configuration(format!("`{}` does not exist", parent_path)))?;
+
+        match (parent, last) {
+            (Value::Object(map), PathSegment::Key(key)) => {
+                map.insert(key.clone(), new_value.clone());
+            }
+            (Value::Array(items), PathSegment::Index(index)) => {
+                if *index < items.len() {
+                    items[*index] = new_value.clone();
+                } else if *index == items.len() {
+                    items.push(new_value.clone());
+                } else {
+                    return Err(GoaError::Configuration(format!(
+                        "`{}` is out of range; `{}` has {} item(s)",
+                        path, parent_path, items.len()
+                    )));
+                }
+            }
+            (_, PathSegment::Key(_)) => {
+                return Err(GoaError::Configuration(format!("`{}` is not an object", parent_path)));
+            }
+            (_, PathSegment::Index(_)) => {
+                return Err(GoaError::Configuration(format!("`{}` is not a list", parent_path)));
+            }
+        }
+
+        let edited = spans::set(&self.source, path.segments(), &new_value, &self.indent);
+        self.update_source(edited)
+    }
+
+    pub(super) fn put_first(&mut self, key: &str, new_value: Value) -> GoaResult<()> {
+        let map = self.value.as_object_mut()
+            .ok_or_else(|| GoaError::Configuration("config.json must contain a JSON object".to_string()))?;
+
+        let mut reordered = Map::new();
+        reordered.insert(key.to_string(), new_value.clone());
+        for (existing, item) in std::mem::take(map) {
+            if existing != key {
+                reordered.insert(existing, item);
+            }
+        }
+        *map = reordered;
+
+        let without = spans::unset(&self.source, &[PathSegment::Key(key.to_string())])
+            .unwrap_or_else(|| self.source.clone());
+        let edited = spans::prepend(&without, key, &new_value, &self.indent);
+        self.update_source(edited)
+    }
+
+    pub(super) fn remove(&mut self, path: &ConfigPath) -> GoaResult<Value> {
+        let (parent_path, last) = path.parent()
+            .ok_or_else(|| GoaError::Configuration("Cannot remove the whole configuration".to_string()))?;
+
+        let removed = match (parent_path.lookup_mut(&mut self.value), last) {
+            (Some(Value::Object(map)), PathSegment::Key(key)) => map.shift_remove(key),
+            (Some(Value::Array(items)), PathSegment::Index(index)) if *index < items.len() => {
+                Some(items.remove(*index))
+            }
+            _ => None,
+        };
+
+        let removed = removed.ok_or_else(|| GoaError::Configuration(format!("`{}` is not set in config.json", path)))?;
+        let edited = spans::unset(&self.source, path.segments());
+        self.update_source(edited)?;
+        Ok(removed)
+    }
+
+    pub fn save(&self) -> GoaResult<()> {
+        fs::write(&self.path, &self.source)
+            .map_err(GoaError::Io)
+    }
+
+    fn update_source(&mut self, edited: Option<String>) -> GoaResult<()> {
+        let matches = |source: &String| serde_json::from_str::<Value>(source).ok().as_ref() == Some(&self.value);
+
+        if let Some(source) = edited.filter(matches) {
+            self.source = source;
+            return Ok(());
+        }
+
+        self.source = render_document(&self.value, &self.indent, self.source.ends_with('\n'))?;
+        Ok(())
+    }
+
+    fn expected_value(&self, path: &ConfigPath) -> GoaResult<Value> {
+        if let Some(current) = path.lookup(&self.value) {
+            return Ok(current.clone());
+        }
+
+        let defaults = serde_json::to_value(GoaConfig::default())
+            .map_err(GoaError::Json)?;
+
+        if let Some(default) = value_at_key(&defaults, &path.to_string()) {
+            return Ok(default.clone());
+        }
+
+        if let Some((parent_path, PathSegment::Key(_))) = path.parent() {
+            if FREE_FORM_MAPS.contains(&parent_path.keys_only().as_str()) {
+                return Ok(Value::String(String::new()));
+            }
+
+            let known = match parent_path.segments().is_empty() {
+                true => Some(&defaults),
+                false => value_at_key(&defaults, &parent_path.to_string()),
+            };
+
+            if let Some(Value::Object(map)) = known {
+                return Err(GoaError::Configuration(format!(
+                    "Unknown config key `{}`. Known keys here: {}",
+                    path,
+                    map.keys().cloned().collect::<Vec<_>>().join(", ")
+                )));
+            }
+        }
+
+        Err(GoaError::Configuration(format!("Unknown config key `{}`", path)))
+    }
+
+    fn create_parents(&mut self, parent_path: &ConfigPath) -> GoaResult<()> {
+        let mut current = &mut self.value;
+
+        for segment in parent_path.segments() {
+            current = match segment {
+                PathSegment::Key(key) => {
+                    let map = current.as_object_mut()
+                        .ok_or_else(|| GoaError::Configuration(format!("`{}` is not an object", parent_path)))?;
+                    map.entry(key.clone()).or_insert_with(|| Value::Object(Map::new()))
+                }
+                PathSegment::Index(index) => current.get_mut(*index)
+                    .ok_or_else(|| GoaError::Configuration(format!("`{}` does not exist", parent_path)))?,
+            };
+        }
+
+        Ok(())
+    }
+}
+
+fn coerce_value(path: &ConfigPath, raw: &str, expected: &Value) -> GoaResult<Value> {
+    let mismatch = |kind: &str| GoaError::Configuration(format!(
+        "`{}` expects {}, got `{}`",
+        path, kind, raw
+    ));
+
+    match expected {
+        Value::String(_) => Ok(Value::String(raw.to_string())),
+        Value::Bool(_) => match raw.trim() {
+            "true" => Ok(Value::Bool(true)),
+            "false" => Ok(Value::Bool(false)),
+            _ => Err(mismatch("true or false")),
+        },
+        Value::Number(_) => raw.trim().parse::<u64>()
+            .map(Value::from)
+            .map_err(|_| mismatch("a non-negative whole number")),
+        Value::Array(_) => match serde_json::from_str::<Value>(raw) {
+            Ok(value @ Value::Array(_)) => Ok(value),
+            _ => Err(mismatch("a JSON list such as [\"a\", \"b\"]")),
+        },
+        Value::Object(_) => match serde_json::from_str::<Value>(raw) {
+            Ok(value @ Value::Object(_)) => Ok(value),
+            _ => Err(mismatch("a JSON object")),
+        },
+        Value::Null => Ok(serde_json::from_str(raw).unwrap_or_else(|_| Value::String(raw.to_string()))),
+    }
+}
+
+fn render_document(value: &Value, indent: &str, trailing_newline: bool) -> GoaResult<String> {
+    let mut output = Vec::new();
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(indent.as_bytes());
+    let mut serializer = serde_json::Serializer::with_formatter(&mut output, formatter);
+    value.serialize(&mut serializer)
+        .map_err(GoaError::Json)?;
+
+    let mut source = String::from_utf8_lossy(&output).into_owned();
+    if trailing_newline {
+        source.push('\n');
+    }
+    Ok(source)
+}
+
+fn detect_indent(source: &str) -> String {
+    source
+        .lines()
+        .skip(1)
+        .find(|line| !line.trim().is_empty())
+        .map(|line| line.chars().take_while(|c| *c == ' ' || *c == '\t').collect::<String>())
+        .filter(|indent| !indent.is_empty())
+        .unwrap_or_else(|| "  ".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const SOURCE: &str = "{\n    \"server\": {\n        \"port\": \"3000\",\n        \"allowedOrigins\": [\"https://a.example\"]\n    },\n    \"meta\": {\"appName\": \"shop\"}\n}\n";
+
+    fn document(source: &str) -> ConfigDocument {
+        ConfigDocument::from_source(Path::new("config.json"), source.to_string()).unwrap()
+    }
+
+    fn path(path: &str) -> ConfigPath {
+        ConfigPath::parse(path).unwrap()
+    }
+
+    #[test]
+    fn parses_keys_and_indexes() {
+        let parsed = path("server.allowedOrigins[2]");
+
+        assert_eq!(parsed.segments(), &[
+            PathSegment::Key("server".to_string()),
+            PathSegment::Key("allowedOrigins".to_string()),
+            PathSegment::Index(2),
+        ]);
+        assert_eq!(parsed.to_string(), "server.allowedOrigins[2]");
+    }
+
+    #[test]
+    fn rejects_malformed_paths() {
+        for invalid in ["", "server..port", "list[x]", "list[1", "list[1]x", ".port"] {
+            assert!(ConfigPath::parse(invalid).is_err(), "`{}` should be rejected", invalid);
+        }
+    }
+
+    #[test]
+    fn set_coerces_to_the_existing_type() {
+        let mut document = document(SOURCE);
+
+        assert_eq!(document.set(&path("server.rateLimit"), "50").unwrap(), json!(50));
+        assert_eq!(document.set(&path("server.devMode"), "false").unwrap(), json!(false));
+        assert_eq!(document.set(&path("server.port"), "8080").unwrap(), json!("8080"));
+
+        assert!(document.set(&path("server.devMode"), "yes").is_err());
+        assert!(document.set(&path("server.rateLimit"), "-1").is_err());
+    }
+
+    #[test]
+    fn set_keeps_the_rest_of_the_file_byte_for_byte() {
+        let mut document = document(SOURCE);
+
+        document.set(&path("server.port"), "8080").unwrap();
+
+        assert_eq!(document.source(), SOURCE.replacen("\"3000\"", "\"8080\"", 1));
+    }
+
+    #[test]
+    fn set_rejects_invalid_field_values() {
+        let mut document = document(SOURCE);
+
+        let error = document.set(&path("server.port"), "abc").unwrap_err();
+        assert!(error.to_string().contains("server.port"));
+        assert_eq!(document.source(), SOURCE);
+
+        let error = document.set(&path("server.port"), "99999").unwrap_err();
+        assert!(error.to_string().contains("between 1 and 65535"));
+        let error = document.set(&path("server.rateLimit"), "0").unwrap_err();
+        assert!(error.to_string().contains("server.rateLimit"));
+        let error = document.set(&path("server.allowedOrigins[1]"), "example.com").unwrap_err();
+        assert!(error.to_string().contains("server.allowedOrigins[1]"));
+
+        assert_eq!(document.source(), SOURCE);
+        assert!(document.set(&path("server.port"), "8080").is_ok());
+    }
+
+    #[test]
+    fn set_rejects_unknown_keys() {
+        let error = document(SOURCE).set(&path("server.prot"), "1").unwrap_err();
+
+        assert!(error.to_string().contains("Unknown config key `server.prot`"));
+        assert!(error.to_string().contains("port"));
+    }
+
+    #[test]
+    fn set_creates_missing_sections() {
+        let mut document = document(SOURCE);
+
+        document.set(&path("ssg.directory"), "public").unwrap();
+        document.set(&path("meta.defaultMetaTags.robots"), "noindex").unwrap();
+
+        let value: Value = serde_json::from_str(document.source()).unwrap();
+        assert_eq!(value["ssg"], json!({"directory": "public"}));
+        assert_eq!(value["meta"]["defaultMetaTags"], json!({"robots": "noindex"}));
+        assert!(document.source().contains("\n    \"ssg\": {\n        \"directory\": \"public\"\n    }\n"));
+    }
+
+    #[test]
+    fn set_appends_and_replaces_list_items() {
+        let mut document = document(SOURCE);
+
+        document.set(&path("server.allowedOrigins[1]"), "https://b.example").unwrap();
+        document.set(&path("server.allowedOrigins[0]"), "*").unwrap();
+
+        assert!(document.source().contains("\"allowedOrigins\": [\"*\", \"https://b.example\"]"));
+        assert!(document.set(&path("server.allowedOrigins[5]"), "https://c.example").is_err());
+    }
+
+    #[test]
+    fn unset_removes_values() {
+        let mut document = document(SOURCE);
+
+        assert_eq!(document.unset(&path("server.allowedOrigins[0]")).unwrap(), json!("https://a.example"));
+        assert_eq!(document.unset(&path("meta")).unwrap(), json!({"appName": "shop"}));
+
+        assert_eq!(
+            document.source(),
+            "{\n    \"server\": {\n        \"port\": \"3000\",\n        \"allowedOrigins\": []\n    }\n}\n"
+        );
+        assert!(document.unset(&path("meta")).is_err());
+    }
+
+    #[test]
+    fn save_writes_the_edited_source() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_path = dir.path().join("config.json");
+        fs::write(&config_path, SOURCE).unwrap();
+
+        let mut document = ConfigDocument::load(&config_path).unwrap();
+        document.set(&path("meta.appName"), "store").unwrap();
+        document.save().unwrap();
+
+        assert_eq!(fs::read_to_string(&config_path).unwrap(), SOURCE.replacen("\"shop\"", "\"store\"", 1));
+    }
+}
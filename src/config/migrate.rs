@@ -0,0 +1,272 @@
+use serde_json::{Map, Value};
+use std::path::Path;
+
+use super::{ConfigDocument, ConfigPath, GoaConfig};
+use crate::errors::{GoaError, GoaResult};
+
+pub const CURRENT_CONFIG_VERSION: u32 = 2;
+
+const VERSION_KEY: &str = "configVersion";
+
+struct Migration {
+    version: u32,
+    description: &'static str,
+    apply: fn(&mut ConfigDocument) -> GoaResult<Vec<String>>,
+}
+
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "Move the legacy top-level name and version into meta",
+        apply: move_legacy_metadata,
+    },
+    Migration {
+        version: 2,
+        description: "Add default values for settings missing from config.json",
+        apply: add_missing_defaults,
+    },
+];
+
+pub struct MigrationStep {
+    pub version: u32,
+    pub description: &'static str,
+    pub changes: Vec<String>,
+}
+
+pub struct MigrationPlan {
+    pub from: u32,
+    pub to: u32,
+    pub steps: Vec<MigrationStep>,
+    pub original: String,
+    pub migrated: String,
+}
+
+pub fn migrate_config(source: &str, path: &Path) -> GoaResult<MigrationPlan> {
+    if let Err(e) = serde_json::from_str::<Value>(source) {
+        return Err(GoaConfig::parse(source, path).err().unwrap_or(GoaError::Json(e)));
+    }
+
+    let mut document = ConfigDocument::from_source(path, source.to_string())?;
+    let version_path = ConfigPath::root().child(VERSION_KEY);
+
+    let from = match document.get(&version_path) {
+        None => 0,
+        Some(version) => version.as_u64().map(|v| v as u32).ok_or_else(|| GoaError::Configuration(format!(
+            "`{}` in {} must be a whole number, found {}",
+            VERSION_KEY,
+            path.display(),
+            version
+        )))?,
+    };
+
+    if from > CURRENT_CONFIG_VERSION {
+        return Err(GoaError::Configuration(format!(
+            "{} uses config version {}, which is newer than this CLI supports ({}). Update the CLI with `goa self update`",
+            path.display(),
+            from,
+            CURRENT_CONFIG_VERSION
+        )));
+    }
+
+    let mut steps = Vec::new();
+    for migration in MIGRATIONS.iter().filter(|m| m.version > from) {
+        steps.push(MigrationStep {
+            version: migration.version,
+            description: migration.description,
+            changes: (migration.apply)(&mut document)?,
+        });
+    }
+
+    if !steps.is_empty() {
+        match document.get(&version_path) {
+            Some(_) => document.put(&version_path, Value::from(CURRENT_CONFIG_VERSION))?,
+            None => document.put_first(VERSION_KEY, Value::from(CURRENT_CONFIG_VERSION))?,
+        }
+        document.config()?;
+    }
+
+    Ok(MigrationPlan {
+        from,
+        to: if steps.is_empty() { from } else { CURRENT_CONFIG_VERSION },
+        steps,
+        original: source.to_string(),
+        migrated: document.source().to_string(),
+    })
+}
+
+fn move_legacy_metadata(document: &mut ConfigDocument) -> GoaResult<Vec<String>> {
+    let mut changes = Vec::new();
+    let root = ConfigPath::root();
+    let meta = root.child("meta");
+
+    let name = document.get(&root.child("name")).cloned();
+    let version = document.get(&root.child("version")).cloned();
+
+    if name.is_none() && version.is_none() {
+        return Ok(changes);
+    }
+
+    if !matches!(document.get(&meta), None | Some(Value::Object(_))) {
+        document.put(&meta, Value::Object(Map::new()))?;
+        changes.push("replaced the invalid `meta` section with an object".to_string());
+    }
+
+    if let Some(name) = name {
+        document.remove(&root.child("name"))?;
+        if document.get(&meta.child("appName")).is_some() {
+            changes.push("removed `name` because `meta.appName` is already set".to_string());
+        } else {
+            document.put(&meta.child("appName"), name)?;
+            changes.push("moved `name` to `meta.appName`".to_string());
+        }
+    }
+
+    if let Some(version) = version {
+        document.remove(&root.child("version"))?;
+        document.put(&meta.child("version"), version)?;
+        changes.push("moved `version` to `meta.version`".to_string());
+    }
+
+    Ok(changes)
+}
+
+fn add_missing_defaults(document: &mut ConfigDocument) -> GoaResult<Vec<String>> {
+    let mut changes = Vec::new();
+
+    if let Value::Object(defaults) = serde_json::to_value(GoaConfig::default()).map_err(GoaError::Json)? {
+        fill_missing(document, &defaults, &ConfigPath::root(), &mut changes)?;
+    }
+
+    Ok(changes)
+}
+
+fn fill_missing(
+    document: &mut ConfigDocument,
+    defaults: &Map<String, Value>,
+    parent: &ConfigPath,
+    changes: &mut Vec<String>,
+) -> GoaResult<()> {
+    for (key, default) in defaults {
+        let path = parent.child(key);
+
+        match (document.get(&path), default) {
+            (None, _) => {
+                document.put(&path, default.clone())?;
+                changes.push(format!("added `{}` with its default value", path));
+            }
+            (Some(Value::Object(_)), Value::Object(default)) if path.to_string() != "meta.defaultMetaTags" => {
+                fill_missing(document, default, &path, changes)?;
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn migrate(source: &str) -> GoaResult<MigrationPlan> {
+        migrate_config(source, Path::new("config.json"))
+    }
+
+    fn parsed(source: &str) -> Value {
+        serde_json::from_str(source).unwrap()
+    }
+
+    #[test]
+    fn moves_legacy_name_and_version_into_meta() {
+        let mut document = ConfigDocument::from_source(
+            Path::new("config.json"),
+            "{\n  \"name\": \"shop\",\n  \"version\": \"1.2.0\",\n  \"server\": {\n    \"port\": \"8080\"\n  }\n}\n".to_string(),
+        )
+        .unwrap();
+
+        let changes = move_legacy_metadata(&mut document).unwrap();
+
+        assert_eq!(changes, vec!["moved `name` to `meta.appName`", "moved `version` to `meta.version`"]);
+        let value = parsed(document.source());
+        assert_eq!(value["meta"]["appName"], "shop");
+        assert_eq!(value["meta"]["version"], "1.2.0");
+        assert!(value.get("name").is_none());
+        assert!(value.get("version").is_none());
+        assert_eq!(value["server"]["port"], "8080");
+    }
+
+    #[test]
+    fn keeps_existing_app_name_when_moving_legacy_name() {
+        let mut document = ConfigDocument::from_source(
+            Path::new("config.json"),
+            r#"{"name": "old", "meta": {"appName": "current"}}"#.to_string(),
+        )
+        .unwrap();
+
+        let changes = move_legacy_metadata(&mut document).unwrap();
+
+        assert_eq!(changes, vec!["removed `name` because `meta.appName` is already set"]);
+        assert_eq!(parsed(document.source())["meta"]["appName"], "current");
+    }
+
+    #[test]
+    fn adds_missing_defaults_and_keeps_formatting() {
+        let source = "{\n    \"server\": {\n        \"port\": \"9000\",\n        \"devMode\": false\n    }\n}\n";
+        let mut document = ConfigDocument::from_source(Path::new("config.json"), source.to_string()).unwrap();
+
+        let changes = add_missing_defaults(&mut document).unwrap();
+
+        assert!(changes.contains(&"added `server.rateLimit` with its default value".to_string()));
+        assert!(changes.contains(&"added `directories` with its default value".to_string()));
+        assert!(!changes.iter().any(|change| change.contains("server.port")));
+
+        let migrated = document.source();
+        assert!(migrated.starts_with("{\n    \"server\": {\n        \"port\": \"9000\",\n        \"devMode\": false,\n"));
+        assert!(migrated.contains("\n        \"rateLimit\": "));
+        assert!(migrated.ends_with("}\n"));
+
+        let value = parsed(migrated);
+        assert_eq!(value["server"]["port"], "9000");
+        assert_eq!(value["server"]["devMode"], false);
+        assert_eq!(value["directories"]["appDir"], "app");
+    }
+
+    #[test]
+    fn migrates_unversioned_config_to_current_version() {
+        let plan = migrate("{\n  \"name\": \"shop\"\n}\n").unwrap();
+
+        assert_eq!(plan.from, 0);
+        assert_eq!(plan.to, CURRENT_CONFIG_VERSION);
+        assert_eq!(plan.steps.iter().map(|step| step.version).collect::<Vec<_>>(), vec![1, 2]);
+        assert!(plan.migrated.starts_with("{\n  \"configVersion\": 2,\n"));
+        assert_eq!(parsed(&plan.migrated)["meta"]["appName"], "shop");
+    }
+
+    #[test]
+    fn current_config_has_no_steps() {
+        let first = migrate("{}\n").unwrap();
+        let plan = migrate(&first.migrated).unwrap();
+
+        assert_eq!(plan.from, CURRENT_CONFIG_VERSION);
+        assert_eq!(plan.to, CURRENT_CONFIG_VERSION);
+        assert!(plan.steps.is_empty());
+        assert_eq!(plan.migrated, plan.original);
+    }
+
+    #[test]
+    fn newer_config_version_is_rejected() {
+        let source = format!("{{\"configVersion\": {}}}", CURRENT_CONFIG_VERSION + 1);
+
+        let error = migrate(&source).err().expect("a newer config version must fail");
+
+        assert!(matches!(error, GoaError::Configuration(_)));
+        assert!(error.to_string().contains("newer than this CLI supports"));
+    }
+
+    #[test]
+    fn non_numeric_config_version_is_rejected() {
+        let error = migrate(r#"{"configVersion": "two"}"#).err().expect("a string version must fail");
+
+        assert!(error.to_string().contains("must be a whole number"));
+    }
+}
@@ -1,21 +1,46 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::io::Write;
 
 use crate::errors::{GoaError, GoaResult};
+use crate::utils;
 
-#[derive(Debug, Serialize, Deserialize)]
+mod document;
+mod migrate;
+mod overlay;
+mod spans;
+
+pub use document::{ConfigDocument, ConfigPath};
+pub use migrate::{migrate_config, MigrationPlan, CURRENT_CONFIG_VERSION};
+pub use overlay::{overlay_path, recover_backup, ConfigOverride, LayeredConfig, DEV_ENV, PRODUCTION_ENV};
+
+pub const DEFAULT_TAILWIND_URL: &str = "https://cdn.tailwindcss.com";
+pub const DEFAULT_JQUERY_URL: &str = "https://code.jquery.com/jquery-3.7.1.min.js";
+pub const DEFAULT_ALPINE_URL: &str = "https://cdn.jsdelivr.net/npm/alpinejs@3.13.3/dist/cdn.min.js";
+pub const DEFAULT_PETITE_VUE_URL: &str = "https://unpkg.com/petite-vue@0.4.1/dist/petite-vue.es.js";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+#[schemars(title = "Go on Airplanes configuration", description = "Schema for the config.json file of a Go on Airplanes project")]
 pub struct GoaConfig {
+    #[serde(rename = "configVersion", skip_serializing_if = "Option::is_none")]
+    pub config_version: Option<u32>,
     pub server: ServerConfig,
     pub directories: DirectoryConfig,
     pub performance: PerformanceConfig,
     pub ssg: SsgConfig,
     pub meta: MetaConfig,
     pub cdn: CdnConfig,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
 pub struct ServerConfig {
     pub port: String,
     #[serde(rename = "devMode")]
@@ -30,9 +55,12 @@ pub struct ServerConfig {
     pub allowed_origins: Vec<String>,
     #[serde(rename = "rateLimit")]
     pub rate_limit: u32,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
 pub struct DirectoryConfig {
     #[serde(rename = "appDir")]
     pub app_dir: String,
@@ -42,33 +70,45 @@ pub struct DirectoryConfig {
     pub layout_path: String,
     #[serde(rename = "componentDir")]
     pub component_dir: String,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
 pub struct PerformanceConfig {
     #[serde(rename = "templateCache")]
     pub template_cache: bool,
     #[serde(rename = "inMemoryJS")]
     pub in_memory_js: bool,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
 pub struct SsgConfig {
     pub enabled: bool,
     #[serde(rename = "cacheEnabled")]
     pub cache_enabled: bool,
     pub directory: String,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
 pub struct MetaConfig {
     #[serde(rename = "appName")]
     pub app_name: String,
     #[serde(rename = "defaultMetaTags")]
     pub default_meta_tags: MetaTags,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
 pub struct MetaTags {
     pub viewport: String,
     pub description: String,
@@ -78,9 +118,12 @@ pub struct MetaTags {
     pub og_type: String,
     #[serde(rename = "twitter:card")]
     pub twitter_card: String,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
 pub struct CdnConfig {
     #[serde(rename = "useCDN")]
     pub use_cdn: bool,
@@ -89,6 +132,121 @@ pub struct CdnConfig {
     pub alpine: String,
     #[serde(rename = "petiteVue")]
     pub petite_vue: String,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub integrity: BTreeMap<String, String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            port: "5000".to_string(),
+            dev_mode: true,
+            is_built_system: false,
+            live_reload: true,
+            enable_cors: false,
+            allowed_origins: vec!["*".to_string()],
+            rate_limit: 100,
+            extra: Map::new(),
+        }
+    }
+}
+
+impl Default for DirectoryConfig {
+    fn default() -> Self {
+        Self {
+            app_dir: "app".to_string(),
+            static_dir: "static".to_string(),
+            layout_path: "app/layout.html".to_string(),
+            component_dir: "app/components".to_string(),
+            extra: Map::new(),
+        }
+    }
+}
+
+impl Default for PerformanceConfig {
+    fn default() -> Self {
+        Self {
+            template_cache: true,
+            in_memory_js: true,
+            extra: Map::new(),
+        }
+    }
+}
+
+impl Default for SsgConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            cache_enabled: true,
+            directory: "static/generated".to_string(),
+            extra: Map::new(),
+        }
+    }
+}
+
+impl Default for MetaConfig {
+    fn default() -> Self {
+        Self {
+            app_name: "Go on Airplanes".to_string(),
+            default_meta_tags: MetaTags::default(),
+            extra: Map::new(),
+        }
+    }
+}
+
+impl Default for MetaTags {
+    fn default() -> Self {
+        Self {
+            viewport: "width=device-width, initial-scale=1.0".to_string(),
+            description: "Go on Airplanes - A modern Go web framework".to_string(),
+            og_title: "Go on Airplanes".to_string(),
+            og_type: "website".to_string(),
+            twitter_card: "summary".to_string(),
+            extra: Map::new(),
+        }
+    }
+}
+
+impl Default for CdnConfig {
+    fn default() -> Self {
+        Self {
+            use_cdn: true,
+            tailwind: DEFAULT_TAILWIND_URL.to_string(),
+            jquery: DEFAULT_JQUERY_URL.to_string(),
+            alpine: DEFAULT_ALPINE_URL.to_string(),
+            petite_vue: DEFAULT_PETITE_VUE_URL.to_string(),
+            integrity: BTreeMap::new(),
+            extra: Map::new(),
+        }
+    }
+}
+
+impl CdnConfig {
+    pub fn assets(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("tailwind", &self.tailwind),
+            ("jquery", &self.jquery),
+            ("alpine", &self.alpine),
+            ("petiteVue", &self.petite_vue),
+        ]
+    }
+
+    pub fn reset_to_defaults(&mut self) {
+        let extra = std::mem::take(&mut self.extra);
+        *self = Self { extra, ..Self::default() };
+    }
+
+    pub fn set_asset(&mut self, key: &str, url: String) {
+        match key {
+            "tailwind" => self.tailwind = url,
+            "jquery" => self.jquery = url,
+            "alpine" => self.alpine = url,
+            "petiteVue" => self.petite_vue = url,
+            _ => {}
+        }
+    }
 }
 
 impl GoaConfig {
@@ -100,41 +258,71 @@ impl GoaConfig {
             ));
         }
 
+        utils::log_debug(&format!("Loading config from {}", path.display()));
         let config_str = fs::read_to_string(path)
             .map_err(|e| GoaError::Configuration(
                 format!("Failed to read config file: {}", e)
             ))?;
 
-        serde_json::from_str(&config_str)
-            .map_err(|e| GoaError::Configuration(
-                format!("Failed to parse config file: {}", e)
-            ))
+        Self::parse(&config_str, path)
+    }
+
+    pub fn parse(source: &str, path: &Path) -> GoaResult<Self> {
+        let deserializer = &mut serde_json::Deserializer::from_str(source);
+        serde_path_to_error::deserialize(deserializer)
+            .map_err(|e| describe_parse_error(source, path, e))
+    }
+
+    pub fn validate(&self) -> GoaResult<()> {
+        let mut problems = Vec::new();
+
+        if let Err(e) = utils::validate_port(&self.server.port) {
+            problems.push(format!("server.port: {}", e));
+        }
+        if let Err(e) = utils::validate_positive_integer(&self.server.rate_limit.to_string()) {
+            problems.push(format!("server.rateLimit: {}", e));
+        }
+        for (i, origin) in self.server.allowed_origins.iter().enumerate() {
+            if let Err(e) = utils::validate_origin(origin) {
+                problems.push(format!("server.allowedOrigins[{}]: {}", i, e));
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(GoaError::Configuration(format!("Invalid configuration:\n  {}", problems.join("\n  "))))
+        }
     }
 
-    #[allow(dead_code)]
     pub fn save(&self, path: impl AsRef<Path>) -> GoaResult<()> {
         let path = path.as_ref();
         let parent = path.parent().ok_or_else(|| {
             GoaError::InvalidPath(format!("Invalid config path: {}", path.display()))
         })?;
 
-        if !parent.exists() {
+        if !parent.as_os_str().is_empty() && !parent.exists() {
             fs::create_dir_all(parent)
-                .map_err(|e| GoaError::Io(e))?;
+                .map_err(GoaError::Io)?;
         }
 
         let config_json = serde_json::to_string_pretty(self)
-            .map_err(|e| GoaError::Json(e))?;
+            .map_err(GoaError::Json)?;
 
         let mut file = fs::File::create(path)
-            .map_err(|e| GoaError::Io(e))?;
+            .map_err(GoaError::Io)?;
 
         file.write_all(config_json.as_bytes())
-            .map_err(|e| GoaError::Io(e))?;
+            .map_err(GoaError::Io)?;
 
         Ok(())
     }
 
+    pub fn schema() -> Value {
+        serde_json::to_value(schemars::schema_for!(GoaConfig))
+            .unwrap_or(Value::Null)
+    }
+
     pub fn get_app_dir(&self) -> PathBuf {
         PathBuf::from(&self.directories.app_dir)
     }
@@ -145,7 +333,119 @@ impl GoaConfig {
         api_dir
     }
 
+    pub fn get_static_url_prefix(&self) -> String {
+        let static_dir = self.directories.static_dir.trim_start_matches("./").trim_matches('/');
+        format!("/{}", static_dir)
+    }
+
     pub fn get_components_dir(&self) -> PathBuf {
         PathBuf::from(&self.directories.component_dir)
     }
-} 
\ No newline at end of file
+}
+
+pub fn find_config_file() -> GoaResult<PathBuf> {
+    let current_dir = std::env::current_dir().map_err(GoaError::Io)?;
+    let config_path = current_dir.join("config.json");
+
+    if config_path.exists() {
+        return Ok(config_path);
+    }
+
+    let mut dir = current_dir;
+    while let Some(parent) = dir.parent() {
+        let parent_config = parent.join("config.json");
+        if parent_config.exists() {
+            return Ok(parent_config);
+        }
+        dir = parent.to_path_buf();
+    }
+
+    Err(GoaError::Configuration("Could not find config.json file. Are you inside a Go on Airplanes project?".to_string()))
+}
+
+fn describe_parse_error(source: &str, path: &Path, error: serde_path_to_error::Error<serde_json::Error>) -> GoaError {
+    let key = error.path().to_string();
+    let inner = error.inner();
+
+    let full_message = inner.to_string();
+    let message = match full_message.rfind(" at line ") {
+        Some(index) => &full_message[..index],
+        None => full_message.as_str(),
+    };
+
+    let mut description = format!("{}:{}:{}: ", path.display(), inner.line(), inner.column());
+    if key != "." && !inner.is_syntax() && !inner.is_eof() {
+        description.push_str(&format!("`{}`: ", key));
+    }
+    description.push_str(message);
+
+    if let Some(line) = source.lines().nth(inner.line().saturating_sub(1)) {
+        description.push_str(&format!("\n    {:>4} | {}", inner.line(), line));
+    }
+
+    if let Some(suggestion) = suggest_fix(&key, inner) {
+        description.push_str(&format!("\n  Suggested fix: {}", suggestion));
+    }
+
+    GoaError::Configuration(description)
+}
+
+fn suggest_fix(key: &str, error: &serde_json::Error) -> Option<String> {
+    let message = error.to_string();
+
+    if error.is_eof() {
+        return Some("the file ends early; check for a missing closing brace or bracket".to_string());
+    }
+
+    if error.is_syntax() {
+        if message.contains("trailing comma") {
+            return Some("remove the trailing comma before the closing brace or bracket".to_string());
+        }
+        if message.contains("key must be a string") {
+            return Some("wrap the key in double quotes".to_string());
+        }
+        return Some("check for a missing comma, quote or brace near this line".to_string());
+    }
+
+    let defaults = serde_json::to_value(GoaConfig::default()).ok()?;
+    let example = value_at_key(&defaults, key)?;
+
+    let kind = match example {
+        Value::String(_) => "a string",
+        Value::Bool(_) => "true or false",
+        Value::Number(_) => "a non-negative whole number",
+        Value::Array(_) => "a list",
+        Value::Object(_) => "an object",
+        Value::Null => return None,
+    };
+
+    let example = match example {
+        Value::Object(_) => "{ ... }".to_string(),
+        other => other.to_string(),
+    };
+
+    Some(format!("set `{}` to {}, for example {}", key, kind, example))
+}
+
+fn value_at_key<'a>(value: &'a Value, key: &str) -> Option<&'a Value> {
+    let mut current = value;
+
+    for segment in key.split('.') {
+        let (name, indexes) = match segment.find('[') {
+            Some(index) => (&segment[..index], &segment[index..]),
+            None => (segment, ""),
+        };
+
+        if !name.is_empty() {
+            current = current.get(name)?;
+        }
+
+        for index in indexes.split('[').filter(|s| !s.is_empty()) {
+            let array = current.as_array()?;
+            let index: usize = index.trim_end_matches(']').parse().ok()?;
+            current = array.get(index).or_else(|| array.first())?;
+        }
+    }
+
+    Some(current)
+}
@@ -0,0 +1,286 @@
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::GoaConfig;
+use crate::errors::{GoaError, GoaResult};
+use crate::utils;
+
+pub const DEV_ENV: &str = "dev";
+pub const PRODUCTION_ENV: &str = "production";
+
+const DEFAULT_SOURCE: &str = "default";
+const BUILTIN_SOURCE: &str = "built-in production defaults";
+
+pub struct LayeredConfig {
+    pub config: GoaConfig,
+    pub env: Option<String>,
+    layers: Vec<(String, Value)>,
+}
+
+impl LayeredConfig {
+    pub fn load(config_path: &Path, env: Option<&str>) -> GoaResult<Self> {
+        recover_backup(config_path)?;
+        let base_source = fs::read_to_string(config_path)
+            .map_err(|e| GoaError::Configuration(format!("Failed to read config file: {}", e)))?;
+        GoaConfig::parse(&base_source, config_path)?;
+
+        let defaults = serde_json::to_value(GoaConfig::default())
+            .map_err(GoaError::Json)?;
+        let base: Value = serde_json::from_str(&base_source)
+            .map_err(GoaError::Json)?;
+
+        let mut layers = vec![
+            (DEFAULT_SOURCE.to_string(), defaults),
+            (file_name(config_path), base),
+        ];
+
+        if let Some(env) = env {
+            validate_env_name(env)?;
+
+            if env != DEV_ENV {
+                layers.push((BUILTIN_SOURCE.to_string(), production_defaults()));
+            }
+
+            let path = overlay_path(config_path, env);
+            if path.exists() {
+                let source = fs::read_to_string(&path)
+                    .map_err(|e| GoaError::Configuration(format!("Failed to read {}: {}", path.display(), e)))?;
+                GoaConfig::parse(&source, &path)?;
+                utils::log_debug(&format!("Applying the {} overlay from {}", env, path.display()));
+                let overlay: Value = serde_json::from_str(&source)
+                    .map_err(GoaError::Json)?;
+                layers.push((file_name(&path), overlay));
+            } else if env != PRODUCTION_ENV {
+                return Err(GoaError::Configuration(format!(
+                    "No overlay found for environment '{}' (expected {})",
+                    env,
+                    path.display()
+                )));
+            }
+        }
+
+        let mut merged = Value::Object(Map::new());
+        for (_, layer) in &layers {
+            merge(&mut merged, layer);
+        }
+
+        let merged_source = serde_json::to_string_pretty(&merged)
+            .map_err(GoaError::Json)?;
+        let config = GoaConfig::parse(&merged_source, config_path)?;
+
+        Ok(Self {
+            config,
+            env: env.map(str::to_string),
+            layers,
+        })
+    }
+
+    pub fn has_overrides(&self) -> bool {
+        self.layers.len() > 2
+    }
+
+    pub fn value(&self) -> GoaResult<Value> {
+        serde_json::to_value(&self.config).map_err(GoaError::Json)
+    }
+
+    pub fn sources(&self) -> GoaResult<Vec<(String, Value, String)>> {
+        let mut origin = BTreeMap::new();
+        for (name, layer) in &self.layers {
+            let mut leaves = Vec::new();
+            flatten("", layer, &mut leaves);
+            for (path, _) in leaves {
+                origin.insert(path, name.clone());
+            }
+        }
+
+        let mut leaves = Vec::new();
+        flatten("", &self.value()?, &mut leaves);
+
+        Ok(leaves
+            .into_iter()
+            .map(|(path, value)| {
+                let source = origin.get(&path).cloned().unwrap_or_else(|| DEFAULT_SOURCE.to_string());
+                (path, value, source)
+            })
+            .collect())
+    }
+}
+
+pub struct ConfigOverride {
+    config_path: PathBuf,
+    backup_path: PathBuf,
+    applied: String,
+}
+
+impl ConfigOverride {
+    pub fn apply(config_path: &Path, config: &GoaConfig) -> GoaResult<Self> {
+        let backup_path = backup_path(config_path);
+        recover_backup(config_path)?;
+
+        fs::copy(config_path, &backup_path).map_err(GoaError::Io)?;
+        let mut guard = Self {
+            config_path: config_path.to_path_buf(),
+            backup_path,
+            applied: String::new(),
+        };
+        config.save(config_path)?;
+        guard.applied = fs::read_to_string(config_path).map_err(GoaError::Io)?;
+
+        Ok(guard)
+    }
+}
+
+impl Drop for ConfigOverride {
+    fn drop(&mut self) {
+        let current = fs::read_to_string(&self.config_path).unwrap_or_default();
+        if !self.applied.is_empty() && current != self.applied {
+            utils::log_warning(&format!(
+                "config.json was edited while the merged config was applied; your edits were kept and the original is at {}",
+                self.backup_path.display()
+            ));
+            return;
+        }
+
+        if fs::copy(&self.backup_path, &self.config_path).is_ok() {
+            fs::remove_file(&self.backup_path).ok();
+            utils::log_debug("Restored the original config.json");
+        } else {
+            utils::log_error(&format!(
+                "Failed to restore config.json, the original is kept at {}",
+                self.backup_path.display()
+            ));
+        }
+    }
+}
+
+pub fn recover_backup(config_path: &Path) -> GoaResult<()> {
+    let backup_path = backup_path(config_path);
+    if !backup_path.exists() {
+        return Ok(());
+    }
+
+    fs::copy(&backup_path, config_path).map_err(GoaError::Io)?;
+    fs::remove_file(&backup_path).map_err(GoaError::Io)?;
+    utils::log_warning("Restored config.json from a previous run that was interrupted while the merged config was applied");
+    Ok(())
+}
+
+fn backup_path(config_path: &Path) -> PathBuf {
+    config_path.with_file_name("config.json.goa-backup")
+}
+
+pub fn overlay_path(config_path: &Path, env: &str) -> PathBuf {
+    config_path.with_file_name(format!("config.{}.json", env))
+}
+
+fn validate_env_name(env: &str) -> GoaResult<()> {
+    if env.is_empty() || !env.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        return Err(GoaError::Configuration(format!(
+            "Invalid environment name '{}'. Use letters, numbers, '-' or '_'",
+            env
+        )));
+    }
+    Ok(())
+}
+
+fn production_defaults() -> Value {
+    json!({
+        "server": {
+            "devMode": false,
+            "liveReload": false,
+            "isBuiltSystem": true
+        }
+    })
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.display().to_string())
+}
+
+fn merge(target: &mut Value, overlay: &Value) {
+    match (target, overlay) {
+        (Value::Object(target), Value::Object(overlay)) => {
+            for (key, value) in overlay {
+                match target.get_mut(key) {
+                    Some(existing) if existing.is_object() && value.is_object() => merge(existing, value),
+                    _ => {
+                        target.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        (target, overlay) => *target = overlay.clone(),
+    }
+}
+
+fn flatten(prefix: &str, value: &Value, leaves: &mut Vec<(String, Value)>) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, child) in map {
+                let path = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+                flatten(&path, child, leaves);
+            }
+        }
+        other => leaves.push((prefix.to_string(), other.clone())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project() -> (tempfile::TempDir, PathBuf) {
+        let dir = tempfile::tempdir().unwrap();
+        let config_path = dir.path().join("config.json");
+        fs::write(&config_path, "{ \"server\": { \"port\": \"3000\" } }\n").unwrap();
+        (dir, config_path)
+    }
+
+    fn merged() -> GoaConfig {
+        let mut config = GoaConfig::default();
+        config.server.port = "9123".to_string();
+        config
+    }
+
+    #[test]
+    fn override_is_restored_on_drop() {
+        let (_dir, config_path) = project();
+        let original = fs::read_to_string(&config_path).unwrap();
+
+        let guard = ConfigOverride::apply(&config_path, &merged()).unwrap();
+        let applied: Value = serde_json::from_str(&fs::read_to_string(&config_path).unwrap()).unwrap();
+        assert_eq!(applied["server"]["port"], "9123");
+
+        drop(guard);
+        assert_eq!(fs::read_to_string(&config_path).unwrap(), original);
+        assert!(!backup_path(&config_path).exists());
+    }
+
+    #[test]
+    fn edits_made_while_applied_are_kept() {
+        let (_dir, config_path) = project();
+
+        let guard = ConfigOverride::apply(&config_path, &merged()).unwrap();
+        fs::write(&config_path, "{ \"server\": { \"port\": \"4000\" } }\n").unwrap();
+        drop(guard);
+
+        assert!(fs::read_to_string(&config_path).unwrap().contains("4000"));
+        assert!(backup_path(&config_path).exists());
+    }
+
+    #[test]
+    fn leftover_backup_is_recovered_before_applying() {
+        let (_dir, config_path) = project();
+        let original = fs::read_to_string(&config_path).unwrap();
+        fs::copy(&config_path, backup_path(&config_path)).unwrap();
+        fs::write(&config_path, "{ \"server\": { \"port\": \"9999\" } }\n").unwrap();
+
+        drop(ConfigOverride::apply(&config_path, &merged()).unwrap());
+
+        assert_eq!(fs::read_to_string(&config_path).unwrap(), original);
+    }
+}
@@ -0,0 +1,577 @@
+use serde_json::Value;
+
+use super::document::PathSegment;
+
+enum Node {
+    Object { start: usize, end: usize, members: Vec<Member> },
+    Array { start: usize, end: usize, items: Vec<Node> },
+    Scalar { start: usize, end: usize },
+}
+
+struct Member {
+    key: String,
+    start: usize,
+    value: Node,
+}
+
+impl Node {
+    fn start(&self) -> usize {
+        match self {
+            Node::Object { start, .. } | Node::Array { start, .. } | Node::Scalar { start, .. } => *start,
+        }
+    }
+
+    fn end(&self) -> usize {
+        match self {
+            Node::Object { end, .. } | Node::Array { end, .. } | Node::Scalar { end, .. } => *end,
+        }
+    }
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn skip_whitespace(&mut self) {
+        while self.pos < self.bytes.len() && self.bytes[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        self.skip_whitespace();
+        if self.bytes.get(self.pos) == Some(&byte) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn value(&mut self) -> Option<Node> {
+        self.skip_whitespace();
+        let start = self.pos;
+
+        match *self.bytes.get(self.pos)? {
+            b'{' => {
+                self.pos += 1;
+                let mut members = Vec::new();
+                if !self.eat(b'}') {
+                    loop {
+                        self.skip_whitespace();
+                        let member_start = self.pos;
+                        let key_end = self.string_end()?;
+                        let key = serde_json::from_str(&self.source[member_start..key_end]).ok()?;
+                        self.pos = key_end;
+                        if !self.eat(b':') {
+                            return None;
+                        }
+                        let value = self.value()?;
+                        members.push(Member { key, start: member_start, value });
+                        if self.eat(b'}') {
+                            break;
+                        }
+                        if !self.eat(b',') {
+                            return None;
+                        }
+                    }
+                }
+                Some(Node::Object { start, end: self.pos, members })
+            }
+            b'[' => {
+                self.pos += 1;
+                let mut items = Vec::new();
+                if !self.eat(b']') {
+                    loop {
+                        items.push(self.value()?);
+                        if self.eat(b']') {
+                            break;
+                        }
+                        if !self.eat(b',') {
+                            return None;
+                        }
+                    }
+                }
+                Some(Node::Array { start, end: self.pos, items })
+            }
+            b'"' => {
+                self.pos = self.string_end()?;
+                Some(Node::Scalar { start, end: self.pos })
+            }
+            _ => {
+                while self.pos < self.bytes.len()
+                    && !matches!(self.bytes[self.pos], b',' | b'}' | b']')
+                    && !self.bytes[self.pos].is_ascii_whitespace()
+                {
+                    self.pos += 1;
+                }
+                Some(Node::Scalar { start, end: self.pos })
+            }
+        }
+    }
+
+    fn string_end(&self) -> Option<usize> {
+        if self.bytes.get(self.pos) != Some(&b'"') {
+            return None;
+        }
+
+        let mut pos = self.pos + 1;
+        while pos < self.bytes.len() {
+            match self.bytes[pos] {
+                b'\\' => pos += 2,
+                b'"' => return Some(pos + 1),
+                _ => pos += 1,
+            }
+        }
+        None
+    }
+}
+
+fn parse(source: &str) -> Option<Node> {
+    let mut parser = Parser { source, bytes: source.as_bytes(), pos: 0 };
+    parser.value()
+}
+
+pub fn set(source: &str, segments: &[PathSegment], value: &Value, indent: &str) -> Option<String> {
+    let root = parse(source)?;
+    let mut node = &root;
+
+    for (i, segment) in segments.iter().enumerate() {
+        let is_last = i + 1 == segments.len();
+
+        match (node, segment) {
+            (Node::Object { members, .. }, PathSegment::Key(key)) => {
+                match members.iter().find(|member| &member.key == key) {
+                    Some(member) => node = &member.value,
+                    None => {
+                        let nested = nest_value(&segments[i + 1..], value)?;
+                        let entry = |line_indent: &str, inline: bool| {
+                            format!("{}: {}", Value::String(key.clone()), render(&nested, line_indent, indent, inline))
+                        };
+                        let ends: Vec<usize> = members.iter().map(|member| member.value.end()).collect();
+                        let firsts: Vec<usize> = members.iter().map(|member| member.start).collect();
+                        return insert_entry(source, node, &firsts, &ends, indent, entry);
+                    }
+                }
+            }
+            (Node::Array { items, .. }, PathSegment::Index(index)) => {
+                if *index < items.len() {
+                    node = &items[*index];
+                } else if *index == items.len() && is_last {
+                    let entry = |line_indent: &str, inline: bool| render(value, line_indent, indent, inline);
+                    let ends: Vec<usize> = items.iter().map(Node::end).collect();
+                    let firsts: Vec<usize> = items.iter().map(Node::start).collect();
+                    return insert_entry(source, node, &firsts, &ends, indent, entry);
+                } else {
+                    return None;
+                }
+            }
+            _ => return None,
+        }
+    }
+
+    let inline = !source[node.start()..node.end()].contains('\n');
+    let rendered = render(value, line_indent(source, node.start()), indent, inline);
+    Some(format!("{}{}{}", &source[..node.start()], rendered, &source[node.end()..]))
+}
+
+pub fn unset(source: &str, segments: &[PathSegment]) -> Option<String> {
+    let root = parse(source)?;
+    let (last, parents) = segments.split_last()?;
+    let mut node = &root;
+
+    for segment in parents {
+        node = match (node, segment) {
+            (Node::Object { members, .. }, PathSegment::Key(key)) => {
+                &members.iter().find(|member| &member.key == key)?.value
+            }
+            (Node::Array { items, .. }, PathSegment::Index(index)) => items.get(*index)?,
+            _ => return None,
+        };
+    }
+
+    let (spans, index): (Vec<(usize, usize)>, usize) = match (node, last) {
+        (Node::Object { members, .. }, PathSegment::Key(key)) => (
+            members.iter().map(|member| (member.start, member.value.end())).collect(),
+            members.iter().position(|member| &member.key == key)?,
+        ),
+        (Node::Array { items, .. }, PathSegment::Index(index)) if *index < items.len() => (
+            items.iter().map(|item| (item.start(), item.end())).collect(),
+            *index,
+        ),
+        _ => return None,
+    };
+
+    let (remove_start, remove_end) = if index > 0 {
+        (spans[index - 1].1, spans[index].1)
+    } else if spans.len() > 1 {
+        (spans[0].0, spans[1].0)
+    } else {
+        (node.start() + 1, node.end() - 1)
+    };
+
+    Some(format!("{}{}", &source[..remove_start], &source[remove_end..]))
+}
+
+pub fn prepend(source: &str, key: &str, value: &Value, indent: &str) -> Option<String> {
+    let root = parse(source)?;
+    let first = match &root {
+        Node::Object { members, .. } => members.first(),
+        _ => return None,
+    };
+
+    let first = match first {
+        Some(first) => first.start,
+        None => return set(source, &[PathSegment::Key(key.to_string())], value, indent),
+    };
+
+    let key = Value::String(key.to_string());
+    let inline = !source[root.start()..root.end()].contains('\n');
+    let item_indent = line_indent(source, first);
+    let entry = format!("{}: {}", key, render(value, item_indent, indent, inline));
+
+    let text = if inline {
+        format!("{}, ", entry)
+    } else {
+        format!("{},\n{}", entry, item_indent)
+    };
+
+    Some(format!("{}{}{}", &source[..first], text, &source[first..]))
+}
+
+fn insert_entry(
+    source: &str,
+    container: &Node,
+    firsts: &[usize],
+    ends: &[usize],
+    indent: &str,
+    entry: impl Fn(&str, bool) -> String,
+) -> Option<String> {
+    let (start, end) = (container.start(), container.end());
+    let inline = !source[start..end].contains('\n');
+
+    let (position, text) = match (firsts.last(), ends.last()) {
+        (Some(&last_start), Some(&last_end)) if inline => (last_end, format!(", {}", entry(line_indent(source, last_start), true))),
+        (Some(&last_start), Some(&last_end)) => {
+            let item_indent = line_indent(source, last_start);
+            (last_end, format!(",\n{}{}", item_indent, entry(item_indent, false)))
+        }
+        _ if matches!(container, Node::Array { .. }) => (start + 1, entry("", true)),
+        _ => {
+            let base = line_indent(source, start);
+            let item_indent = format!("{}{}", base, indent);
+            return Some(format!(
+                "{}{{\n{}{}\n{}}}{}",
+                &source[..start],
+                item_indent,
+                entry(&item_indent, false),
+                base,
+                &source[end..]
+            ));
+        }
+    };
+
+    Some(format!("{}{}{}", &source[..position], text, &source[position..]))
+}
+
+fn nest_value(segments: &[PathSegment], value: &Value) -> Option<Value> {
+    let mut nested = value.clone();
+    for segment in segments.iter().rev() {
+        match segment {
+            PathSegment::Key(key) => {
+                let mut map = serde_json::Map::new();
+                map.insert(key.clone(), nested);
+                nested = Value::Object(map);
+            }
+            PathSegment::Index(_) => return None,
+        }
+    }
+    Some(nested)
+}
+
+fn line_indent(source: &str, position: usize) -> &str {
+    let line_start = source[..position].rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let line = &source[line_start..position];
+    let trimmed = line.trim_start_matches([' ', '\t']);
+    &line[..line.len() - trimmed.len()]
+}
+
+fn render(value: &Value, line_indent: &str, indent: &str, inline: bool) -> String {
+    match value {
+        Value::Array(items) if inline => format!(
+            "[{}]",
+            items.iter().map(|item| render(item, line_indent, indent, true)).collect::<Vec<_>>().join(", ")
+        ),
+        Value::Object(map) if inline => format!(
+            "{{{}}}",
+            map.iter()
+                .map(|(key, item)| format!("{}: {}", Value::String(key.clone()), render(item, line_indent, indent, true)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Value::Array(items) if !items.is_empty() => {
+            let child_indent = format!("{}{}", line_indent, indent);
+            let rendered: Vec<String> = items
+                .iter()
+                .map(|item| format!("{}{}", child_indent, render(item, &child_indent, indent, false)))
+                .collect();
+            format!("[\n{}\n{}]", rendered.join(",\n"), line_indent)
+        }
+        Value::Object(map) if !map.is_empty() => {
+            let child_indent = format!("{}{}", line_indent, indent);
+            let rendered: Vec<String> = map
+                .iter()
+                .map(|(key, item)| format!(
+                    "{}{}: {}",
+                    child_indent,
+                    Value::String(key.clone()),
+                    render(item, &child_indent, indent, false)
+                ))
+                .collect();
+            format!("{{\n{}\n{}}}", rendered.join(",\n"), line_indent)
+        }
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn key(name: &str) -> PathSegment {
+        PathSegment::Key(name.to_string())
+    }
+
+    fn index(index: usize) -> PathSegment {
+        PathSegment::Index(index)
+    }
+
+    fn assert_valid(source: &str) -> Value {
+        serde_json::from_str(source).unwrap_or_else(|e| panic!("invalid JSON ({}):\n{}", e, source))
+    }
+
+    const MULTI_LINE: &str = r#"{
+  "server": {
+    "port": "3000",
+    "devMode": true,
+    "allowedOrigins": [
+      "https://a.example",
+      "https://b.example"
+    ]
+  },
+  "meta": {"appName": "shop", "tags": ["x", "y"]}
+}
+"#;
+
+    #[test]
+    fn sets_scalar_in_multi_line_object_without_touching_the_rest() {
+        let edited = set(MULTI_LINE, &[key("server"), key("port")], &json!("8080"), "  ").unwrap();
+
+        assert_eq!(edited, MULTI_LINE.replacen("\"3000\"", "\"8080\"", 1));
+    }
+
+    #[test]
+    fn sets_scalar_in_inline_object() {
+        let edited = set(MULTI_LINE, &[key("meta"), key("appName")], &json!("store"), "  ").unwrap();
+
+        assert_eq!(edited, MULTI_LINE.replacen("\"shop\"", "\"store\"", 1));
+    }
+
+    #[test]
+    fn adds_key_to_multi_line_object_on_its_own_line() {
+        let edited = set(MULTI_LINE, &[key("server"), key("rateLimit")], &json!(100), "  ").unwrap();
+
+        let expected = MULTI_LINE.replacen("    ]\n  },", "    ],\n    \"rateLimit\": 100\n  },", 1);
+        assert_eq!(edited, expected);
+        assert_valid(&edited);
+    }
+
+    #[test]
+    fn adds_key_to_inline_object_inline() {
+        let edited = set(MULTI_LINE, &[key("meta"), key("version")], &json!("1.0"), "  ").unwrap();
+
+        let expected = MULTI_LINE.replacen("\"tags\": [\"x\", \"y\"]}", "\"tags\": [\"x\", \"y\"], \"version\": \"1.0\"}", 1);
+        assert_eq!(edited, expected);
+    }
+
+    #[test]
+    fn replaces_object_value_keeping_its_layout() {
+        let edited = set(MULTI_LINE, &[key("meta")], &json!({"appName": "a", "tags": []}), "  ").unwrap();
+
+        assert!(edited.contains("\"meta\": {\"appName\": \"a\", \"tags\": []}\n"));
+        assert!(edited.starts_with(&MULTI_LINE[..MULTI_LINE.find("\"meta\"").unwrap()]));
+    }
+
+    #[test]
+    fn creates_nested_missing_keys() {
+        let source = "{\n  \"server\": {\n    \"port\": \"3000\"\n  }\n}\n";
+
+        let edited = set(source, &[key("ssg"), key("cache"), key("enabled")], &json!(true), "  ").unwrap();
+
+        assert_eq!(
+            edited,
+            "{\n  \"server\": {\n    \"port\": \"3000\"\n  },\n  \"ssg\": {\n    \"cache\": {\n      \"enabled\": true\n    }\n  }\n}\n"
+        );
+    }
+
+    #[test]
+    fn creates_key_in_empty_object() {
+        let source = "{\n  \"server\": {}\n}\n";
+
+        let edited = set(source, &[key("server"), key("port")], &json!("3000"), "  ").unwrap();
+
+        assert_eq!(edited, "{\n  \"server\": {\n    \"port\": \"3000\"\n  }\n}\n");
+    }
+
+    #[test]
+    fn nested_keys_below_a_missing_array_index_are_not_created() {
+        assert!(set("{}", &[key("list"), index(0), key("name")], &json!("x"), "  ").is_none());
+    }
+
+    #[test]
+    fn sets_array_item() {
+        let path = [key("server"), key("allowedOrigins"), index(1)];
+
+        let edited = set(MULTI_LINE, &path, &json!("https://c.example"), "  ").unwrap();
+
+        assert_eq!(edited, MULTI_LINE.replacen("https://b.example", "https://c.example", 1));
+    }
+
+    #[test]
+    fn appends_to_multi_line_array() {
+        let path = [key("server"), key("allowedOrigins"), index(2)];
+
+        let edited = set(MULTI_LINE, &path, &json!("https://c.example"), "  ").unwrap();
+
+        let expected = MULTI_LINE.replacen(
+            "\"https://b.example\"\n",
+            "\"https://b.example\",\n      \"https://c.example\"\n",
+            1,
+        );
+        assert_eq!(edited, expected);
+    }
+
+    #[test]
+    fn appends_to_inline_and_empty_arrays() {
+        let edited = set(MULTI_LINE, &[key("meta"), key("tags"), index(2)], &json!("z"), "  ").unwrap();
+        assert!(edited.contains("\"tags\": [\"x\", \"y\", \"z\"]"));
+
+        let edited = set("{\"tags\": []}", &[key("tags"), index(0)], &json!("a"), "  ").unwrap();
+        assert_eq!(edited, "{\"tags\": [\"a\"]}");
+    }
+
+    #[test]
+    fn index_past_the_end_is_rejected() {
+        assert!(set(MULTI_LINE, &[key("meta"), key("tags"), index(5)], &json!("z"), "  ").is_none());
+        assert!(unset(MULTI_LINE, &[key("meta"), key("tags"), index(2)]).is_none());
+    }
+
+    #[test]
+    fn removes_array_items_and_their_commas() {
+        let tags = [key("meta"), key("tags")];
+        let with = |i| [tags[0].clone(), tags[1].clone(), index(i)];
+
+        let first = unset(MULTI_LINE, &with(0)).unwrap();
+        assert!(first.contains("\"tags\": [\"y\"]"));
+
+        let last = unset(MULTI_LINE, &with(1)).unwrap();
+        assert!(last.contains("\"tags\": [\"x\"]"));
+
+        let only = unset(&last, &with(0)).unwrap();
+        assert!(only.contains("\"tags\": []"));
+        assert_valid(&only);
+    }
+
+    #[test]
+    fn removes_multi_line_array_items() {
+        let origins = |i| [key("server"), key("allowedOrigins"), index(i)];
+
+        let first = unset(MULTI_LINE, &origins(0)).unwrap();
+        assert!(first.contains("\"allowedOrigins\": [\n      \"https://b.example\"\n    ]"));
+
+        let last = unset(MULTI_LINE, &origins(1)).unwrap();
+        assert!(last.contains("\"allowedOrigins\": [\n      \"https://a.example\"\n    ]"));
+    }
+
+    #[test]
+    fn unsets_first_middle_and_last_members() {
+        let first = unset(MULTI_LINE, &[key("server"), key("port")]).unwrap();
+        assert!(first.contains("\"server\": {\n    \"devMode\": true,\n"));
+        assert_valid(&first);
+
+        let middle = unset(MULTI_LINE, &[key("server"), key("devMode")]).unwrap();
+        assert!(middle.contains("\"port\": \"3000\",\n    \"allowedOrigins\""));
+        assert_valid(&middle);
+
+        let last = unset(MULTI_LINE, &[key("meta")]).unwrap();
+        assert!(last.ends_with("    ]\n  }\n}\n"));
+        assert_valid(&last);
+    }
+
+    #[test]
+    fn unsets_members_of_inline_objects() {
+        let first = unset(MULTI_LINE, &[key("meta"), key("appName")]).unwrap();
+        assert!(first.contains("\"meta\": {\"tags\": [\"x\", \"y\"]}"));
+
+        let last = unset(MULTI_LINE, &[key("meta"), key("tags")]).unwrap();
+        assert!(last.contains("\"meta\": {\"appName\": \"shop\"}"));
+
+        let only = unset("{\"a\": {\"b\": 1}}", &[key("a"), key("b")]).unwrap();
+        assert_eq!(only, "{\"a\": {}}");
+    }
+
+    #[test]
+    fn unset_of_missing_key_is_none() {
+        assert!(unset(MULTI_LINE, &[key("server"), key("missing")]).is_none());
+        assert!(unset(MULTI_LINE, &[key("missing"), key("port")]).is_none());
+    }
+
+    #[test]
+    fn handles_escapes_and_unicode() {
+        let source = "{\n  \"quote\\\"d\": \"a \\\"}\\\\ ,]\",\n  \"naïve\": \"café ☕\",\n  \"after\": 1\n}";
+
+        let edited = set(source, &[key("after")], &json!(2), "  ").unwrap();
+        assert_eq!(edited, source.replacen("\"after\": 1", "\"after\": 2", 1));
+
+        let edited = set(source, &[key("naïve")], &json!("thé \"vert\" 🍵"), "  ").unwrap();
+        assert!(edited.contains("\"naïve\": \"thé \\\"vert\\\" 🍵\","));
+        assert_eq!(assert_valid(&edited)["naïve"], "thé \"vert\" 🍵");
+
+        let edited = set(source, &[key("quote\"d")], &json!("x"), "  ").unwrap();
+        assert_eq!(assert_valid(&edited)["quote\"d"], "x");
+        assert!(edited.contains("\"naïve\": \"café ☕\""));
+
+        let edited = unset(source, &[key("naïve")]).unwrap();
+        assert_eq!(assert_valid(&edited), json!({"quote\"d": "a \"}\\ ,]", "after": 1}));
+    }
+
+    #[test]
+    fn keeps_custom_indentation() {
+        let source = "{\n\t\"server\": {\n\t\t\"port\": \"3000\"\n\t}\n}";
+
+        let edited = set(source, &[key("server"), key("devMode")], &json!(false), "\t").unwrap();
+        assert_eq!(edited, "{\n\t\"server\": {\n\t\t\"port\": \"3000\",\n\t\t\"devMode\": false\n\t}\n}");
+
+        let edited = set(source, &[key("meta")], &json!({"appName": "x"}), "\t").unwrap();
+        assert_eq!(edited, "{\n\t\"server\": {\n\t\t\"port\": \"3000\"\n\t},\n\t\"meta\": {\n\t\t\"appName\": \"x\"\n\t}\n}");
+    }
+
+    #[test]
+    fn prepends_key_to_object() {
+        let edited = prepend(MULTI_LINE, "configVersion", &json!(2), "  ").unwrap();
+        assert!(edited.starts_with("{\n  \"configVersion\": 2,\n  \"server\": {\n"));
+        assert_eq!(&edited[edited.find("\"server\"").unwrap()..], &MULTI_LINE[MULTI_LINE.find("\"server\"").unwrap()..]);
+
+        assert_eq!(prepend("{\"a\": 1}", "v", &json!(2), "  ").unwrap(), "{\"v\": 2, \"a\": 1}");
+    }
+
+    #[test]
+    fn invalid_source_is_not_edited() {
+        assert!(set("{\"a\": ", &[key("a")], &json!(1), "  ").is_none());
+        assert!(unset("{\"a\" 1}", &[key("a")]).is_none());
+    }
+}
@@ -23,8 +23,68 @@ pub enum GoaError {
     #[error("Invalid path: {0}")]
     InvalidPath(String),
 
+    #[error("Self-update failed: {0}")]
+    Update(String),
+
+    #[error("{0}")]
+    Cancelled(String),
+
     #[error("{0}")]
     Other(String),
 }
 
-pub type GoaResult<T> = Result<T, GoaError>; 
\ No newline at end of file
+impl GoaError {
+    pub fn variant(&self) -> &'static str {
+        match self {
+            GoaError::Io(_) => "Io",
+            GoaError::Json(_) => "Json",
+            GoaError::ProjectCreation(_) => "ProjectCreation",
+            GoaError::RouteGeneration(_) => "RouteGeneration",
+            GoaError::ComponentGeneration(_) => "ComponentGeneration",
+            GoaError::Configuration(_) => "Configuration",
+            GoaError::InvalidPath(_) => "InvalidPath",
+            GoaError::Update(_) => "Update",
+            GoaError::Cancelled(_) => "Cancelled",
+            GoaError::Other(_) => "Other",
+        }
+    }
+
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            GoaError::Other(_) => 1,
+            GoaError::Configuration(_) => 3,
+            GoaError::InvalidPath(_) => 4,
+            GoaError::ProjectCreation(_) => 5,
+            GoaError::RouteGeneration(_) => 6,
+            GoaError::ComponentGeneration(_) => 7,
+            GoaError::Io(_) => 8,
+            GoaError::Json(_) => 9,
+            GoaError::Update(_) => 10,
+            GoaError::Cancelled(_) => 130,
+        }
+    }
+}
+
+pub type GoaResult<T> = Result<T, GoaError>;
+
+pub fn exit_code(error: &anyhow::Error) -> u8 {
+    error.chain()
+        .find_map(|cause| {
+            cause.downcast_ref::<GoaError>().map(GoaError::exit_code)
+                .or_else(|| cause.is::<std::io::Error>().then_some(8))
+        })
+        .unwrap_or(1)
+}
+
+pub fn variant(error: &anyhow::Error) -> &'static str {
+    error.chain()
+        .find_map(|cause| {
+            cause.downcast_ref::<GoaError>().map(GoaError::variant)
+                .or_else(|| cause.is::<std::io::Error>().then_some("Io"))
+        })
+        .unwrap_or("Other")
+}
+
+pub fn is_cancelled(error: &anyhow::Error) -> bool {
+    matches!(error.downcast_ref::<GoaError>(), Some(GoaError::Cancelled(_)))
+} 
\ No newline at end of file
@@ -1,23 +1,54 @@
 use anyhow::Result;
-use clap::{Parser, Subcommand};
+use clap::{ArgMatches, CommandFactory, FromArgMatches, Parser, Subcommand};
+use clap_complete::CompleteEnv;
 use colored::Colorize;
-use std::process::Command;
+use serde_json::json;
+use std::ffi::OsString;
+use std::process::ExitCode;
 
 mod commands;
 mod config;
 mod errors;
+mod requirements;
+mod settings;
 mod templates;
+mod updater;
 mod utils;
 mod version;
 
+use requirements::{Requirement, RequirementOptions};
+use utils::logger::{self, OutputFormat, Verbosity};
+use version::UpdateStatus;
+
 #[derive(Parser)]
 #[clap(name = "goa", about = "Go on Airplanes CLI - Developer-focused tooling for the Go on Airplanes framework", version, disable_version_flag = true)]
 struct Cli {
     #[clap(subcommand)]
     command: Option<Commands>,
 
-    #[clap(long = "version", short = 'v', help = "Print version information", global = true)]
+    #[clap(long = "version", short = 'v', help = "Print version information")]
     version_flag: bool,
+
+    #[clap(long, help = "Skip the Go and Git requirement checks (or set GOA_SKIP_CHECKS=1)", global = true)]
+    skip_checks: bool,
+
+    #[clap(long, help = "Install missing requirements automatically (or set GOA_AUTO_INSTALL=1)", global = true)]
+    install_missing: bool,
+
+    #[clap(long, short = 'q', conflicts_with = "verbose", help = "Only print warnings, errors and command output", global = true)]
+    quiet: bool,
+
+    #[clap(long, help = "Print debug details and the commands that are executed", global = true)]
+    verbose: bool,
+
+    #[clap(long, help = "Disable colored output (or set NO_COLOR=1)", global = true)]
+    no_color: bool,
+
+    #[clap(long, help = "Do not print the banner", global = true)]
+    no_banner: bool,
+
+    #[clap(long = "output", id = "output_format", value_name = "FORMAT", value_enum, default_value = "human", help = "Print human-readable text or newline-delimited JSON events", global = true)]
+    output_format: OutputFormat,
 }
 
 #[derive(Subcommand)]
@@ -43,6 +74,36 @@ enum Commands {
         command: commands::component::ComponentCommands,
     },
     
+    #[clap(about = "Run the development server and rebuild assets on change")]
+    Dev(commands::dev::DevArgs),
+    
+    #[clap(about = "Check your environment and project for common problems")]
+    Doctor(commands::doctor::DoctorArgs),
+    
+    #[clap(about = "Manage front-end assets such as CDN libraries")]
+    Assets {
+        #[clap(subcommand)]
+        command: commands::assets::AssetsCommands,
+    },
+    
+    #[clap(about = "Manage environment variables stored in .env")]
+    Env {
+        #[clap(subcommand)]
+        command: commands::env::EnvCommands,
+    },
+    
+    #[clap(about = "Inspect and validate config.json")]
+    Config {
+        #[clap(subcommand)]
+        command: commands::config::ConfigCommands,
+    },
+    
+    #[clap(about = "Print a shell completion script, e.g. `source <(goa completions bash)`")]
+    Completions(commands::completions::CompletionsArgs),
+    
+    #[clap(about = "Generate man pages")]
+    Man(commands::completions::ManArgs),
+    
     #[clap(name = "self", about = "Update the CLI to the latest version")]
     SelfCmd {
         #[clap(subcommand)]
@@ -50,26 +111,100 @@ enum Commands {
     },
 }
 
+impl Commands {
+    fn requirements(&self) -> &'static [Requirement] {
+        match self {
+            Commands::Project { command } => command.requirements(),
+            Commands::Dev(_) => &[Requirement::Go],
+            _ => &[],
+        }
+    }
+}
+
 #[derive(Subcommand)]
 enum SelfCommands {
     #[clap(about = "Update the CLI to the latest version")]
-    Update,
+    Update {
+        #[clap(long, value_enum, default_value = "stable", help = "Release channel to update from")]
+        channel: version::Channel,
+        
+        #[clap(long, value_name = "VERSION", conflicts_with = "channel", help = "Install a specific version, e.g. v0.1.1")]
+        version: Option<String>,
+    },
+    
+    #[clap(about = "Restore the version that was installed before the last update")]
+    Rollback,
+    
+    #[clap(about = "Turn the background check for new CLI versions on or off")]
+    UpdateCheck {
+        #[clap(value_parser = ["on", "off"])]
+        state: String,
+    },
 }
 
-fn main() -> Result<()> {
-    let cli = Cli::parse();
+fn main() -> ExitCode {
+    CompleteEnv::with_factory(Cli::command)
+        .var(commands::completions::COMPLETE_ENV)
+        .complete();
+    
+    let (args, renamed_flag) = rewrite_legacy_output(std::env::args_os().collect());
+    let matches = Cli::command().get_matches_from(args);
+    let command_name = command_name(&matches);
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    
+    let verbosity = if cli.quiet {
+        Verbosity::Quiet
+    } else if cli.verbose {
+        Verbosity::Verbose
+    } else {
+        Verbosity::Normal
+    };
+    let output_format = match &cli.command {
+        Some(Commands::Doctor(commands::doctor::DoctorArgs { json: true })) => OutputFormat::Json,
+        _ => cli.output_format,
+    };
+    logger::init(verbosity, cli.no_color, output_format);
     
+    if let Some(flag) = renamed_flag {
+        utils::log_warning(&format!(
+            "`--output <PATH>` is deprecated here and now selects the output format; use `{}` instead",
+            flag
+        ));
+    }
+    
+    let result = run(cli);
+    logger::finish(&command_name, &result);
+    
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => ExitCode::from(errors::exit_code(&e)),
+    }
+}
+
+fn run(cli: Cli) -> Result<()> {
     if cli.version_flag {
         print_version_info();
         return Ok(());
     }
     
-    print_banner();
+    let machine_output = logger::is_json() || matches!(
+        &cli.command,
+        Some(Commands::Completions(_) | Commands::Man(_))
+    );
+    if !machine_output && !cli.no_banner && !cli.quiet {
+        print_banner();
+    }
     
-    let _ = version::check_version();
+    if let Some(command) = &cli.command {
+        let options = RequirementOptions {
+            skip_checks: cli.skip_checks,
+            install_missing: cli.install_missing,
+        };
+        requirements::verify_requirements(command.requirements(), &options)?;
+    }
     
-    if !verify_requirements()? {
-        return Ok(());
+    if !machine_output {
+        let _ = version::check_version();
     }
     
     match cli.command {
@@ -83,9 +218,32 @@ fn main() -> Result<()> {
             Commands::Component { command } => {
                 commands::component::handle_component_command(command)
             },
+            Commands::Dev(args) => {
+                commands::dev::handle_dev_command(args)
+            },
+            Commands::Doctor(_) => {
+                commands::doctor::handle_doctor_command()
+            },
+            Commands::Assets { command } => {
+                commands::assets::handle_assets_command(command)
+            },
+            Commands::Env { command } => {
+                commands::env::handle_env_command(command)
+            },
+            Commands::Completions(args) => {
+                commands::completions::handle_completions_command(args, Cli::command())
+            },
+            Commands::Man(args) => {
+                commands::completions::handle_man_command(args, Cli::command())
+            },
+            Commands::Config { command } => {
+                commands::config::handle_config_command(command)
+            },
             Commands::SelfCmd { command } => {
                 match command {
-                    SelfCommands::Update => version::handle_self_update(),
+                    SelfCommands::Update { channel, version } => version::handle_self_update(channel, version),
+                    SelfCommands::Rollback => updater::rollback(),
+                    SelfCommands::UpdateCheck { state } => version::set_update_check(state == "on"),
                 }
             },
         },
@@ -96,201 +254,122 @@ fn main() -> Result<()> {
     }
 }
 
-fn verify_requirements() -> Result<bool> {
-    let mut all_requirements_met = true;
-    
+fn print_version_info() {
+    let current_version = version::get_current_version();
     
-    if !is_command_available("git") {
-        all_requirements_met = false;
-        utils::log_error("Git is not installed on your system");
-        
-        if utils::prompt_confirm("Would you like to install Git now?", true)? {
-            if install_git()? {
-                utils::log_success("Git installed successfully");
-            } else {
-                utils::log_error("Failed to install Git automatically");
-                utils::log_info("Please install Git manually from https://git-scm.com/downloads");
-                return Ok(false);
-            }
-        } else {
-            utils::log_info("Git is required for Go on Airplanes. Please install it from https://git-scm.com/downloads");
-            return Ok(false);
-        }
+    if logger::is_json() {
+        let (status, latest_version) = match version::get_update_status() {
+            Ok(UpdateStatus::Available(latest_version)) => ("available", Some(latest_version)),
+            Ok(UpdateStatus::Ahead(latest_version)) => ("ahead", Some(latest_version)),
+            Ok(UpdateStatus::UpToDate) => ("up-to-date", None),
+            Err(_) => ("unknown", None),
+        };
+        logger::set_result(json!({ "version": current_version, "latest": latest_version, "status": status }));
+        return;
     }
     
+    println!("GOA CLI v{}", current_version);
     
-    if !is_command_available("go") {
-        all_requirements_met = false;
-        utils::log_error("Go is not installed on your system");
-        
-        if utils::prompt_confirm("Would you like to install Go now?", true)? {
-            if install_go()? {
-                utils::log_success("Go installed successfully");
-            } else {
-                utils::log_error("Failed to install Go automatically");
-                utils::log_info("Please install Go manually from https://golang.org/dl/");
-                return Ok(false);
-            }
-        } else {
-            utils::log_info("Go is required for Go on Airplanes. Please install it from https://golang.org/dl/");
-            return Ok(false);
-        }
-    } else {
-        
-        if let Ok(output) = Command::new("go").arg("version").output() {
-            if output.status.success() {
-                let version = String::from_utf8_lossy(&output.stdout);
-                utils::log_success(&format!("Found {}", version.trim()));
-            }
+    match version::get_update_status() {
+        Ok(UpdateStatus::Available(latest_version)) => {
+            println!("Latest version: {}", latest_version.bright_green());
+            println!("Run {} to upgrade.", "`goa self update`".cyan());
+        },
+        Ok(UpdateStatus::Ahead(latest_version)) => {
+            println!("You are running a development build ahead of the latest release ({}).", latest_version);
+        },
+        Ok(UpdateStatus::UpToDate) => {
+            println!("You are using the latest version.");
+        },
+        Err(e) => {
+            println!("Could not check for updates: {}", e);
         }
     }
-    
-    Ok(all_requirements_met)
 }
 
-fn is_command_available(command: &str) -> bool {
-    #[cfg(windows)]
-    let check_command = Command::new("where").arg(command).output();
-    
-    #[cfg(not(windows))]
-    let check_command = Command::new("which").arg(command).output();
+fn print_banner() {
+    let banner = r#"
+   ██████╗  ██████╗      ██████╗ ███╗   ██╗     █████╗ ██╗██████╗ ██████╗ ██╗      █████╗ ███╗   ██╗███████╗███████╗
+  ██╔════╝ ██╔═══██╗    ██╔═══██╗████╗  ██║    ██╔══██╗██║██╔══██╗██╔══██╗██║     ██╔══██╗████╗  ██║██╔════╝██╔════╝
+  ██║  ███╗██║   ██║    ██║   ██║██╔██╗ ██║    ███████║██║██████╔╝██████╔╝██║     ███████║██╔██╗ ██║█████╗  ███████╗
+  ██║   ██║██║   ██║    ██║   ██║██║╚██╗██║    ██╔══██║██║██╔══██╗██╔═══╝ ██║     ██╔══██║██║╚██╗██║██╔══╝  ╚════██║
+  ╚██████╔╝╚██████╔╝    ╚██████╔╝██║ ╚████║    ██║  ██║██║██║  ██║██║     ███████╗██║  ██║██║ ╚████║███████╗███████║
+   ╚═════╝  ╚═════╝      ╚═════╝ ╚═╝  ╚═══╝    ╚═╝  ╚═╝╚═╝╚═╝  ╚═╝╚═╝     ╚══════╝╚═╝  ╚═╝╚═╝  ╚═══╝╚══════╝╚══════╝
+    "#;
     
-    match check_command {
-        Ok(output) => output.status.success(),
-        Err(_) => false,
-    }
+    println!("{}", banner.cyan());
+    println!("{} v{}", "Go on Airplanes CLI - Fly high with simple web development".bright_blue(), version::get_current_version().bright_yellow());
+    println!();
 }
 
-fn install_git() -> Result<bool> {
-    utils::log_step("Installing Git...");
+fn rewrite_legacy_output(mut args: Vec<OsString>) -> (Vec<OsString>, Option<&'static str>) {
+    let position = |first: &str, second: &str| {
+        args.windows(2).position(|pair| pair[0] == first && pair[1] == second).map(|index| index + 2)
+    };
+    let (start, flag) = match (position("project", "build"), position("config", "schema")) {
+        (Some(start), _) => (start, "--out-dir"),
+        (None, Some(start)) => (start, "--out-file"),
+        (None, None) => return (args, None),
+    };
     
-    #[cfg(target_os = "windows")]
-    {
-        utils::log_info("Automatic installation is not supported on Windows");
-        utils::log_info("Please download and install Git from https://git-scm.com/download/win");
-        return Ok(false);
-    }
-    
-    #[cfg(target_os = "macos")]
-    {
-        if is_command_available("brew") {
-            return Ok(Command::new("brew").args(["install", "git"]).status()?.success());
-        } else {
-            utils::log_info("Homebrew not found. Installing via Homebrew is recommended");
-            utils::log_info("Please download and install Git from https://git-scm.com/download/mac");
-            return Ok(false);
+    let is_format = |value: &str| matches!(value, "human" | "json");
+    let mut renamed = None;
+    let mut index = start;
+    while index < args.len() {
+        let arg = args[index].to_string_lossy().into_owned();
+        if arg == "--" {
+            break;
         }
-    }
-    
-    #[cfg(target_os = "linux")]
-    {
-        if is_command_available("apt-get") {
-            
-            if let Ok(status) = Command::new("sudo").args(["apt-get", "update"]).status() {
-                if status.success() {
-                    return Ok(Command::new("sudo").args(["apt-get", "install", "-y", "git"]).status()?.success());
-                }
-            }
-        } else if is_command_available("yum") {
-            
-            return Ok(Command::new("sudo").args(["yum", "install", "-y", "git"]).status()?.success());
-        } else if is_command_available("dnf") {
-            
-            return Ok(Command::new("sudo").args(["dnf", "install", "-y", "git"]).status()?.success());
-        } else if is_command_available("pacman") {
-            
-            return Ok(Command::new("sudo").args(["pacman", "-S", "--noconfirm", "git"]).status()?.success());
+        if arg == "--output" && args.get(index + 1).map(|value| !is_format(&value.to_string_lossy())).unwrap_or(false) {
+            args[index] = OsString::from(flag);
+            renamed = Some(flag);
+            index += 1;
+        } else if let Some(value) = arg.strip_prefix("--output=").filter(|value| !is_format(value)) {
+            args[index] = OsString::from(format!("{}={}", flag, value));
+            renamed = Some(flag);
         }
-        
-        utils::log_info("No supported package manager found");
-        utils::log_info("Please install Git manually from https://git-scm.com/download/linux");
-        return Ok(false);
+        index += 1;
     }
     
-    #[allow(unreachable_code)]
-    Ok(false)
+    (args, renamed)
 }
 
-fn install_go() -> Result<bool> {
-    utils::log_step("Installing Go...");
-    
-    #[cfg(target_os = "windows")]
-    {
-        utils::log_info("Automatic installation is not supported on Windows");
-        utils::log_info("Please download and install Go from https://golang.org/dl/");
-        return Ok(false);
+fn command_name(matches: &ArgMatches) -> String {
+    let mut names = Vec::new();
+    let mut current = matches;
+    while let Some((name, sub_matches)) = current.subcommand() {
+        names.push(name.to_string());
+        current = sub_matches;
     }
-    
-    #[cfg(target_os = "macos")]
-    {
-        if is_command_available("brew") {
-            return Ok(Command::new("brew").args(["install", "go"]).status()?.success());
-        } else {
-            utils::log_info("Homebrew not found. Installing via Homebrew is recommended");
-            utils::log_info("Please download and install Go from https://golang.org/dl/");
-            return Ok(false);
-        }
+    names.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<OsString> {
+        line.split_whitespace().map(OsString::from).collect()
     }
-    
-    #[cfg(target_os = "linux")]
-    {
-        if is_command_available("apt-get") {
-            
-            if let Ok(status) = Command::new("sudo").args(["apt-get", "update"]).status() {
-                if status.success() {
-                    return Ok(Command::new("sudo").args(["apt-get", "install", "-y", "golang"]).status()?.success());
-                }
-            }
-        } else if is_command_available("yum") {
-            
-            return Ok(Command::new("sudo").args(["yum", "install", "-y", "golang"]).status()?.success());
-        } else if is_command_available("dnf") {
-            
-            return Ok(Command::new("sudo").args(["dnf", "install", "-y", "golang"]).status()?.success());
-        } else if is_command_available("pacman") {
-            
-            return Ok(Command::new("sudo").args(["pacman", "-S", "--noconfirm", "go"]).status()?.success());
-        }
-        
-        utils::log_info("No supported package manager found");
-        utils::log_info("Please install Go manually from https://golang.org/dl/");
-        return Ok(false);
+
+    #[test]
+    fn legacy_build_output_becomes_out_dir() {
+        assert_eq!(rewrite_legacy_output(args("goa project build --output dist")), (args("goa project build --out-dir dist"), Some("--out-dir")));
+        assert_eq!(rewrite_legacy_output(args("goa project build --output=dist")), (args("goa project build --out-dir=dist"), Some("--out-dir")));
+        assert_eq!(rewrite_legacy_output(args("goa config schema --output schema.json")), (args("goa config schema --out-file schema.json"), Some("--out-file")));
     }
-    
-    #[allow(unreachable_code)]
-    Ok(false)
-}
 
-fn print_version_info() {
-    let current_version = version::get_current_version();
-    println!("GOA CLI v{}", current_version);
-    
-    match version::get_latest_version() {
-        Ok(latest_version) => {
-            if latest_version != format!("v{}", current_version) {
-                println!("Latest version: {}", latest_version.bright_green());
-                println!("Run {} to upgrade.", "`goa self update`".cyan());
-            } else {
-                println!("You are using the latest version.");
-            }
-        },
-        Err(_) => {
-            println!("Could not check for updates.");
+    #[test]
+    fn output_formats_are_left_alone() {
+        for line in ["goa project build --output json", "goa --output json project build -o dist", "goa route api new users --output dist", "goa project build --output=human"] {
+            assert_eq!(rewrite_legacy_output(args(line)), (args(line), None), "{}", line);
         }
     }
-}
 
-fn print_banner() {
-    let banner = r#"
-   ██████╗  ██████╗      ██████╗ ███╗   ██╗     █████╗ ██╗██████╗ ██████╗ ██╗      █████╗ ███╗   ██╗███████╗███████╗
-  ██╔════╝ ██╔═══██╗    ██╔═══██╗████╗  ██║    ██╔══██╗██║██╔══██╗██╔══██╗██║     ██╔══██╗████╗  ██║██╔════╝██╔════╝
-  ██║  ███╗██║   ██║    ██║   ██║██╔██╗ ██║    ███████║██║██████╔╝██████╔╝██║     ███████║██╔██╗ ██║█████╗  ███████╗
-  ██║   ██║██║   ██║    ██║   ██║██║╚██╗██║    ██╔══██║██║██╔══██╗██╔═══╝ ██║     ██╔══██║██║╚██╗██║██╔══╝  ╚════██║
-  ╚██████╔╝╚██████╔╝    ╚██████╔╝██║ ╚████║    ██║  ██║██║██║  ██║██║     ███████╗██║  ██║██║ ╚████║███████╗███████║
-   ╚═════╝  ╚═════╝      ╚═════╝ ╚═╝  ╚═══╝    ╚═╝  ╚═╝╚═╝╚═╝  ╚═╝╚═╝     ╚══════╝╚═╝  ╚═╝╚═╝  ╚═══╝╚══════╝╚══════╝
-    "#;
-    
-    println!("{}", banner.cyan());
-    println!("{} v{}", "Go on Airplanes CLI - Fly high with simple web development".bright_blue(), version::get_current_version().bright_yellow());
-    println!();
+    #[test]
+    fn rewritten_build_args_parse() {
+        let (rewritten, _) = rewrite_legacy_output(args("goa project build --output dist"));
+        let cli = Cli::try_parse_from(rewritten).unwrap();
+        assert!(cli.output_format == OutputFormat::Human);
+    }
 }
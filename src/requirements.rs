@@ -0,0 +1,358 @@
+use anyhow::Result;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::config::find_config_file;
+use crate::errors::GoaError;
+use crate::utils;
+
+pub const MIN_GO_VERSION: &str = "1.21";
+
+const SKIP_CHECKS_ENV: &str = "GOA_SKIP_CHECKS";
+const AUTO_INSTALL_ENV: &str = "GOA_AUTO_INSTALL";
+const GO_RELEASES_URL: &str = "https://go.dev/dl/?mode=json";
+pub const GO_DOWNLOAD_URL: &str = "https://go.dev/dl/";
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Requirement {
+    Git,
+    Go,
+}
+
+pub struct RequirementOptions {
+    pub skip_checks: bool,
+    pub install_missing: bool,
+}
+
+#[derive(Deserialize)]
+struct GoRelease {
+    version: String,
+    stable: bool,
+    files: Vec<GoFile>,
+}
+
+#[derive(Deserialize)]
+struct GoFile {
+    filename: String,
+    os: String,
+    arch: String,
+    sha256: String,
+    kind: String,
+}
+
+pub fn verify_requirements(requirements: &[Requirement], options: &RequirementOptions) -> Result<()> {
+    if requirements.is_empty() || options.skip_checks || env_flag(SKIP_CHECKS_ENV) {
+        return Ok(());
+    }
+
+    let install_missing = options.install_missing || env_flag(AUTO_INSTALL_ENV);
+
+    if requirements.contains(&Requirement::Git) && !utils::is_command_available("git") {
+        utils::log_warning("Git is not installed on your system");
+        if !install_missing {
+            return Err(missing_requirement("Git", "https://git-scm.com/downloads"));
+        }
+        if !install_git()? {
+            utils::log_info("Please install Git manually from https://git-scm.com/downloads");
+            return Err(GoaError::Other("Failed to install Git automatically".to_string()).into());
+        }
+        utils::log_success("Git installed successfully");
+    }
+
+    if requirements.contains(&Requirement::Go) {
+        let minimum = minimum_go_version();
+
+        match go_version() {
+            Some(installed) if parse_version(&installed) >= parse_version(&minimum) => {
+                utils::log_debug(&format!("Found Go {}", installed));
+            }
+            installed => {
+                match &installed {
+                    Some(installed) => utils::log_warning(&format!("Go {} is installed but {} or newer is required", installed, minimum)),
+                    None => utils::log_warning("Go is not installed on your system"),
+                }
+                if !install_missing {
+                    return Err(missing_requirement(&format!("Go {} or newer", minimum), GO_DOWNLOAD_URL));
+                }
+                install_go(&minimum)?;
+                verify_installed_go(&minimum)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub fn go_version() -> Option<String> {
+    let mut command = Command::new("go");
+    command.arg("version");
+    utils::log_command(&command);
+    let output = command.output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    String::from_utf8_lossy(&output.stdout)
+        .split_whitespace()
+        .find_map(|part| part.strip_prefix("go").filter(|v| v.starts_with(|c: char| c.is_ascii_digit())))
+        .map(str::to_string)
+}
+
+pub fn required_go_version(project_dir: &Path) -> Option<String> {
+    fs::read_to_string(project_dir.join("go.mod"))
+        .ok()?
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("go ").map(|v| v.trim().to_string()))
+}
+
+pub fn parse_version(version: &str) -> Vec<u32> {
+    let mut parts: Vec<u32> = version
+        .trim_start_matches("go")
+        .split('.')
+        .map(|part| part.chars().take_while(char::is_ascii_digit).collect::<String>().parse().unwrap_or(0))
+        .collect();
+    if parts.len() < 3 {
+        parts.resize(3, 0);
+    }
+    parts
+}
+
+fn minimum_go_version() -> String {
+    let project_version = find_config_file()
+        .ok()
+        .and_then(|path| path.parent().and_then(required_go_version));
+
+    match project_version {
+        Some(version) if parse_version(&version) > parse_version(MIN_GO_VERSION) => version,
+        _ => MIN_GO_VERSION.to_string(),
+    }
+}
+
+fn env_flag(name: &str) -> bool {
+    env::var(name).map(|value| !matches!(value.as_str(), "" | "0" | "false")).unwrap_or(false)
+}
+
+fn missing_requirement(name: &str, url: &str) -> anyhow::Error {
+    utils::log_info(&format!(
+        "Install {} from {}, rerun with --install-missing (or {}=1) to install it automatically, or pass --skip-checks (or {}=1) to continue anyway",
+        name, url, AUTO_INSTALL_ENV, SKIP_CHECKS_ENV
+    ));
+    GoaError::Other(format!("{} is required for this command", name)).into()
+}
+
+fn install_go(minimum: &str) -> Result<()> {
+    utils::log_step(&format!("Installing Go {} or newer from {}", minimum, GO_DOWNLOAD_URL));
+
+    let (os, arch) = go_platform()
+        .ok_or_else(|| GoaError::Other("Automatic Go installation is not supported on this platform".to_string()))?;
+
+    let releases: Vec<GoRelease> = reqwest::blocking::get(GO_RELEASES_URL)
+        .and_then(|response| response.error_for_status())
+        .and_then(|response| response.json())
+        .map_err(|e| GoaError::Other(format!("Failed to fetch the list of Go releases: {}", e)))?;
+
+    let (release, file) = releases
+        .iter()
+        .filter(|release| release.stable && parse_version(&release.version) >= parse_version(minimum))
+        .find_map(|release| {
+            release.files.iter()
+                .find(|file| file.os == os && file.arch == arch && file.kind == "archive")
+                .map(|file| (release, file))
+        })
+        .ok_or_else(|| GoaError::Other(format!("No official Go {} or newer download found for {}/{}", minimum, os, arch)))?;
+
+    utils::log_info(&format!("Downloading {}", file.filename));
+    let archive = reqwest::blocking::get(format!("{}{}", GO_DOWNLOAD_URL, file.filename))
+        .and_then(|response| response.error_for_status())
+        .and_then(|response| response.bytes())
+        .map_err(|e| GoaError::Other(format!("Failed to download {}: {}", file.filename, e)))?;
+
+    let digest: String = Sha256::digest(&archive).iter().map(|byte| format!("{:02x}", byte)).collect();
+    if digest != file.sha256 {
+        return Err(GoaError::Other(format!(
+            "Checksum mismatch for {} (expected {}, got {})",
+            file.filename, file.sha256, digest
+        )).into());
+    }
+
+    let install_root = go_install_root();
+    let go_root = install_root.join("go");
+    if go_root.exists() {
+        fs::remove_dir_all(&go_root).map_err(GoaError::Io)?;
+    }
+    utils::ensure_directory_exists(&install_root)?;
+
+    let archive_path = install_root.join(&file.filename);
+    fs::write(&archive_path, &archive).map_err(GoaError::Io)?;
+    let extracted = extract_archive(&archive_path, &install_root);
+    fs::remove_file(&archive_path).ok();
+    extracted?;
+
+    let bin_dir = go_root.join("bin");
+    let path = env::var_os("PATH").unwrap_or_default();
+    let mut paths = vec![bin_dir.clone()];
+    paths.extend(env::split_paths(&path));
+    env::set_var("PATH", env::join_paths(paths).map_err(|e| GoaError::Other(e.to_string()))?);
+
+    utils::log_success(&format!("Installed {} to {}", release.version, go_root.display()));
+    utils::log_info(&format!("Add {} to your PATH to use it outside of goa", bin_dir.display()));
+    Ok(())
+}
+
+fn verify_installed_go(minimum: &str) -> Result<()> {
+    match go_version() {
+        Some(installed) if parse_version(&installed) >= parse_version(minimum) => {
+            utils::log_success(&format!("Using Go {}", installed));
+            Ok(())
+        }
+        Some(installed) => Err(GoaError::Other(format!(
+            "Go {} was found after the installation, but {} or newer is required",
+            installed, minimum
+        )).into()),
+        None => Err(GoaError::Other("The installed Go toolchain could not be run (`go version` failed)".to_string()).into()),
+    }
+}
+
+fn go_platform() -> Option<(&'static str, &'static str)> {
+    let os = match env::consts::OS {
+        "linux" => "linux",
+        "macos" => "darwin",
+        "windows" => "windows",
+        "freebsd" => "freebsd",
+        _ => return None,
+    };
+    let arch = match env::consts::ARCH {
+        "x86_64" => "amd64",
+        "aarch64" => "arm64",
+        "x86" => "386",
+        "arm" => "armv6l",
+        _ => return None,
+    };
+    Some((os, arch))
+}
+
+fn go_install_root() -> PathBuf {
+    let mut path = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
+    path.push("goa-cli");
+    path
+}
+
+fn extract_archive(archive: &Path, destination: &Path) -> Result<()> {
+    #[cfg(windows)]
+    let mut command = {
+        let mut command = Command::new("powershell");
+        command.args(["-Command", &format!(
+            "Expand-Archive -Force -Path {} -DestinationPath {}",
+            powershell_quote(archive),
+            powershell_quote(destination)
+        )]);
+        command
+    };
+
+    #[cfg(not(windows))]
+    let mut command = {
+        let mut command = Command::new("tar");
+        command.arg("-xzf").arg(archive).arg("-C").arg(destination);
+        command
+    };
+
+    utils::log_command(&command);
+    let status = command.status();
+
+    match status {
+        Ok(status) if status.success() => Ok(()),
+        _ => Err(GoaError::Other(format!("Failed to extract {}", archive.display())).into()),
+    }
+}
+
+#[cfg(any(windows, test))]
+fn powershell_quote(path: &Path) -> String {
+    format!("'{}'", path.display().to_string().replace('\'', "''"))
+}
+
+fn install_git() -> Result<bool> {
+    utils::log_step("Installing Git...");
+
+    #[cfg(target_os = "windows")]
+    {
+        utils::log_info("Automatic installation is not supported on Windows");
+        utils::log_info("Please download and install Git from https://git-scm.com/download/win");
+        return Ok(false);
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        if utils::is_command_available("brew") {
+            return Ok(Command::new("brew").args(["install", "git"]).status()?.success());
+        } else {
+            utils::log_info("Homebrew not found. Installing via Homebrew is recommended");
+            utils::log_info("Please download and install Git from https://git-scm.com/download/mac");
+            return Ok(false);
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        if utils::is_command_available("apt-get") {
+            if let Ok(status) = Command::new("sudo").args(["apt-get", "update"]).status() {
+                if status.success() {
+                    return Ok(Command::new("sudo").args(["apt-get", "install", "-y", "git"]).status()?.success());
+                }
+            }
+        } else if utils::is_command_available("yum") {
+            return Ok(Command::new("sudo").args(["yum", "install", "-y", "git"]).status()?.success());
+        } else if utils::is_command_available("dnf") {
+            return Ok(Command::new("sudo").args(["dnf", "install", "-y", "git"]).status()?.success());
+        } else if utils::is_command_available("pacman") {
+            return Ok(Command::new("sudo").args(["pacman", "-S", "--noconfirm", "git"]).status()?.success());
+        }
+
+        utils::log_info("No supported package manager found");
+        utils::log_info("Please install Git manually from https://git-scm.com/download/linux");
+        return Ok(false);
+    }
+
+    #[allow(unreachable_code)]
+    Ok(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_version_pads_to_three_components() {
+        assert_eq!(parse_version("1.21"), vec![1, 21, 0]);
+        assert_eq!(parse_version("go1.22.3"), vec![1, 22, 3]);
+        assert_eq!(parse_version("1.21rc2"), vec![1, 21, 0]);
+        assert_eq!(parse_version("1.2.3.4"), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn versions_with_and_without_patch_compare_equal() {
+        assert_eq!(parse_version("1.21"), parse_version("1.21.0"));
+        assert!(parse_version("1.21.0") >= parse_version("1.21"));
+        assert!(parse_version("1.21.5") > parse_version("1.21"));
+        assert!(parse_version("1.9") < parse_version("1.10"));
+        assert!(parse_version("go1.20.14") < parse_version(MIN_GO_VERSION));
+    }
+
+    #[test]
+    fn required_go_version_reads_go_mod() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(required_go_version(dir.path()), None);
+
+        fs::write(dir.path().join("go.mod"), "module example.com/app\n\ngo 1.22.1\n\nrequire example.com/lib v1.0.0\n").unwrap();
+        assert_eq!(required_go_version(dir.path()).as_deref(), Some("1.22.1"));
+    }
+
+    #[test]
+    fn powershell_quote_escapes_single_quotes() {
+        assert_eq!(powershell_quote(Path::new("C:/Users/me/go.zip")), "'C:/Users/me/go.zip'");
+        assert_eq!(powershell_quote(Path::new("C:/Users/O'Brien/go.zip")), "'C:/Users/O''Brien/go.zip'");
+    }
+}
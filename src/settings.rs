@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::errors::{GoaError, GoaResult};
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update_check: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version_url: Option<String>,
+}
+
+impl UserSettings {
+    pub fn load() -> Self {
+        fs::read_to_string(settings_path())
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> GoaResult<()> {
+        let path = settings_path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(GoaError::Io)?;
+        }
+        let content = serde_json::to_string_pretty(self).map_err(GoaError::Json)?;
+        fs::write(&path, format!("{}\n", content)).map_err(GoaError::Io)
+    }
+}
+
+pub fn settings_path() -> PathBuf {
+    let mut path = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+    path.push("goa-cli");
+    path.push("settings.json");
+    path
+}
@@ -1,3 +1,5 @@
+mod render;
+
 pub mod api {
     pub fn route(package_name: &str) -> String {
         format!(
@@ -104,14 +106,188 @@ pub mod page {
 }
 
 pub mod component {
-    pub fn basic_component() -> &'static str {
-        r#"{{ define "card" }}
+    use crate::errors::GoaResult;
+
+    use super::render::render;
+
+    #[derive(Clone, Copy, clap::ValueEnum)]
+    pub enum Blueprint {
+        Card,
+        Modal,
+        Navbar,
+        FormField,
+        Table,
+    }
+
+    impl Blueprint {
+        fn source(self) -> &'static str {
+            match self {
+                Blueprint::Card => CARD,
+                Blueprint::Modal => MODAL,
+                Blueprint::Navbar => NAVBAR,
+                Blueprint::FormField => FORM_FIELD,
+                Blueprint::Table => TABLE,
+            }
+        }
+
+        pub fn uses_dict(self) -> bool {
+            matches!(self, Blueprint::Modal | Blueprint::FormField)
+        }
+    }
+
+    const CARD: &str = r#"{{ define "[[name]]" }}
 <div class="bg-white overflow-hidden shadow rounded-lg">
     <div class="px-4 py-5 sm:p-6">
         {{.}}
     </div>
 </div>
-{{ end }}"#
+{{ end }}"#;
+
+    const MODAL: &str = r#"{{/* goa:props id:string,title:string,body:html,footer?:html */}}
+{{/*
+  Open the modal by removing the "hidden" class from the element with the given id.
+  Usage: {{ template "[[name]]" dict "Id" "confirm" "Title" "Are you sure?" "Body" .Message }}
+*/}}
+{{ define "[[name]]" }}
+<div id="{{ .Id }}" class="[[name]] fixed inset-0 z-50 hidden items-center justify-center bg-black/50" role="dialog" aria-modal="true" aria-labelledby="{{ .Id }}-title">
+    <div class="bg-white rounded-lg shadow-xl w-full max-w-lg mx-4">
+        <div class="flex items-center justify-between px-6 py-4 border-b">
+            <h3 id="{{ .Id }}-title" class="text-lg font-medium">{{ .Title }}</h3>
+            <button type="button" class="text-gray-400 hover:text-gray-600" aria-label="Close" onclick="this.closest('[role=dialog]').classList.add('hidden')">&times;</button>
+        </div>
+        <div class="px-6 py-4">
+            {{ .Body }}
+        </div>
+        {{ with .Footer }}
+        <div class="flex justify-end gap-2 px-6 py-4 border-t">
+            {{ . }}
+        </div>
+        {{ end }}
+    </div>
+</div>
+{{ end }}"#;
+
+    const NAVBAR: &str = r#"{{/*
+  Data: .Brand (string) and .Links (a list of items with .Href and .Label)
+  Usage: {{ template "[[name]]" .Nav }}
+*/}}
+{{ define "[[name]]" }}
+<nav class="[[name]] bg-white shadow">
+    <div class="max-w-7xl mx-auto px-4 sm:px-6 lg:px-8">
+        <div class="flex h-16 items-center justify-between">
+            <a href="/" class="text-xl font-bold">{{ .Brand }}</a>
+            <div class="flex space-x-4">
+                {{ range .Links }}
+                <a href="{{ .Href }}" class="px-3 py-2 rounded-md text-sm font-medium text-gray-700 hover:bg-gray-100">{{ .Label }}</a>
+                {{ end }}
+            </div>
+        </div>
+    </div>
+</nav>
+{{ end }}"#;
+
+    const FORM_FIELD: &str = r#"{{/* goa:props name:string,label:string,type?:string,value?:string,placeholder?:string,error?:string */}}
+{{/*
+  Usage: {{ template "[[name]]" dict "Name" "email" "Label" "Email" "Type" "email" }}
+*/}}
+{{ define "[[name]]" }}
+<div class="[[name]] mb-4">
+    <label for="{{ .Name }}" class="block text-sm font-medium text-gray-700">{{ .Label }}</label>
+    <input id="{{ .Name }}" name="{{ .Name }}" type="{{ or .Type "text" }}" value="{{ .Value }}" placeholder="{{ .Placeholder }}"
+        class="mt-1 block w-full rounded-md border {{ if .Error }}border-red-500{{ else }}border-gray-300{{ end }} px-3 py-2 shadow-sm focus:border-blue-500 focus:outline-none">
+    {{ with .Error }}
+    <p class="mt-1 text-sm text-red-600">{{ . }}</p>
+    {{ end }}
+</div>
+{{ end }}"#;
+
+    const TABLE: &str = r#"{{/*
+  Data: .Columns (a list of column headings) and .Rows (a list of rows, each a list of cells)
+  Usage: {{ template "[[name]]" .Users }}
+*/}}
+{{ define "[[name]]" }}
+<div class="[[name]] overflow-x-auto shadow rounded-lg">
+    <table class="min-w-full divide-y divide-gray-200">
+        <thead class="bg-gray-50">
+            <tr>
+                {{ range .Columns }}
+                <th scope="col" class="px-6 py-3 text-left text-xs font-medium uppercase tracking-wider text-gray-500">{{ . }}</th>
+                {{ end }}
+            </tr>
+        </thead>
+        <tbody class="bg-white divide-y divide-gray-200">
+            {{ range .Rows }}
+            <tr>
+                {{ range . }}
+                <td class="px-6 py-4 whitespace-nowrap text-sm text-gray-700">{{ . }}</td>
+                {{ end }}
+            </tr>
+            {{ else }}
+            <tr>
+                <td class="px-6 py-4 text-sm text-gray-500" colspan="{{ len .Columns }}">No rows</td>
+            </tr>
+            {{ end }}
+        </tbody>
+    </table>
+</div>
+{{ end }}"#;
+
+    const PROPS_CARD: &str = r#"[[contract|raw]]
+{{/*
+[[docs|raw]]
+*/}}
+{{ define "[[name]]" }}
+<div class="bg-white overflow-hidden shadow rounded-lg">
+    <div class="px-4 py-5 sm:p-6">
+[[body|raw]]
+    </div>
+</div>
+{{ end }}"#;
+
+    pub fn blueprint_component(blueprint: Blueprint, name: &str) -> GoaResult<String> {
+        render(blueprint.source(), &[("name", name)])
+    }
+
+    pub fn props_component(name: &str, contract: &str, docs: &str, body: &str) -> GoaResult<String> {
+        render(PROPS_CARD, &[("name", name), ("contract", contract), ("docs", docs), ("body", body)])
+    }
+
+    pub fn dict_helper(package: &str) -> String {
+        format!(
+            r#"// Code generated by goa component new. You may edit this file.
+
+package {package}
+
+import (
+	"fmt"
+	"html/template"
+)
+
+// FuncMap returns the template functions used by generated components.
+// Register it before parsing templates, e.g. tmpl.Funcs({package}.FuncMap()).
+func FuncMap() template.FuncMap {{
+	return template.FuncMap{{"dict": Dict}}
+}}
+
+// Dict builds component props from key/value pairs so templates can call
+// {{{{ template "card" dict "Title" .Title "Body" .Body }}}}.
+func Dict(values ...interface{{}}) (map[string]interface{{}}, error) {{
+	if len(values)%2 != 0 {{
+		return nil, fmt.Errorf("dict: expected key/value pairs, got %d arguments", len(values))
+	}}
+
+	props := make(map[string]interface{{}}, len(values)/2)
+	for i := 0; i < len(values); i += 2 {{
+		key, ok := values[i].(string)
+		if !ok {{
+			return nil, fmt.Errorf("dict: key %v is not a string", values[i])
+		}}
+		props[key] = values[i+1]
+	}}
+	return props, nil
+}}
+"#
+        )
     }
 }
 
@@ -133,4 +309,37 @@ pub mod project {
             project_name
         )
     }
-} 
\ No newline at end of file
+} 
+#[cfg(test)]
+mod tests {
+    use super::component::{self, Blueprint};
+    use clap::ValueEnum;
+
+    #[test]
+    fn every_blueprint_renders_without_placeholders() {
+        for blueprint in Blueprint::value_variants() {
+            let output = component::blueprint_component(*blueprint, "widget").unwrap();
+
+            assert!(!output.contains("[["), "{:?} left a placeholder", blueprint.to_possible_value());
+            assert!(output.contains("{{ define \"widget\" }}"));
+        }
+    }
+
+    #[test]
+    fn blueprints_that_call_dict_are_flagged() {
+        for blueprint in Blueprint::value_variants() {
+            let output = component::blueprint_component(*blueprint, "widget").unwrap();
+
+            assert_eq!(blueprint.uses_dict(), output.contains(" dict "), "{:?}", blueprint.to_possible_value());
+        }
+    }
+
+    #[test]
+    fn props_component_inserts_raw_sections() {
+        let output = component::props_component("card", "{{/* goa:props title */}}", "  Props: .Title", "<h2>{{ .Title }}</h2>").unwrap();
+
+        assert!(output.starts_with("{{/* goa:props title */}}\n{{/*\n  Props: .Title\n*/}}\n{{ define \"card\" }}"));
+        assert!(output.contains("<h2>{{ .Title }}</h2>"));
+        assert!(!output.contains("[["));
+    }
+}
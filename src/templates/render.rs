@@ -0,0 +1,112 @@
+use regex::{Captures, Regex};
+
+use crate::errors::{GoaError, GoaResult};
+
+pub fn render(template: &str, values: &[(&str, &str)]) -> GoaResult<String> {
+    let placeholder = Regex::new(r"\\\[\[|\[\[\s*([a-z_][a-z0-9_]*)\s*(?:\|\s*([a-z]+)\s*)?\]\]").unwrap();
+    let mut output = String::with_capacity(template.len());
+    let mut last = 0;
+
+    for captures in placeholder.captures_iter(template) {
+        let whole = captures.get(0).unwrap();
+        output.push_str(&template[last..whole.start()]);
+        output.push_str(&substitute(&captures, values)?);
+        last = whole.end();
+    }
+
+    output.push_str(&template[last..]);
+    Ok(output)
+}
+
+fn substitute(captures: &Captures, values: &[(&str, &str)]) -> GoaResult<String> {
+    let name = match captures.get(1) {
+        Some(name) => name.as_str(),
+        None => return Ok("[[".to_string()),
+    };
+
+    let value = values
+        .iter()
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| *value)
+        .ok_or_else(|| GoaError::Other(format!("Template placeholder [[{}]] has no value", name)))?;
+
+    match captures.get(2).map(|filter| filter.as_str()) {
+        None | Some("html") => Ok(escape_html(value)),
+        Some("raw") => Ok(value.to_string()),
+        Some(filter) => Err(GoaError::Other(format!("Unknown template filter '{}' in [[{}]]", filter, name))),
+    }
+}
+
+fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&#34;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn substitutes_placeholders() {
+        let output = render("{{ define \"[[name]]\" }}<div class=\"[[ name ]]\"></div>", &[("name", "card")]).unwrap();
+
+        assert_eq!(output, "{{ define \"card\" }}<div class=\"card\"></div>");
+    }
+
+    #[test]
+    fn escapes_html_unless_raw() {
+        let values = [("body", "<b class=\"x\">Tom & Jerry's</b>")];
+
+        assert_eq!(
+            render("[[body]]", &values).unwrap(),
+            "&lt;b class=&#34;x&#34;&gt;Tom &amp; Jerry&#39;s&lt;/b&gt;"
+        );
+        assert_eq!(render("[[body|html]]", &values).unwrap(), render("[[body]]", &values).unwrap());
+        assert_eq!(render("[[ body | raw ]]", &values).unwrap(), "<b class=\"x\">Tom & Jerry's</b>");
+    }
+
+    #[test]
+    fn escaped_brackets_are_kept_literally() {
+        let output = render("\\[[name]] is [[name]]", &[("name", "card")]).unwrap();
+
+        assert_eq!(output, "[[name]] is card");
+    }
+
+    #[test]
+    fn values_are_not_rendered_again() {
+        let output = render("[[a|raw]]", &[("a", "[[b]]"), ("b", "nested")]).unwrap();
+
+        assert_eq!(output, "[[b]]");
+    }
+
+    #[test]
+    fn unknown_placeholders_are_errors() {
+        let error = render("[[title]]", &[("name", "card")]).unwrap_err();
+
+        assert_eq!(error.to_string(), GoaError::Other("Template placeholder [[title]] has no value".to_string()).to_string());
+    }
+
+    #[test]
+    fn unknown_filters_are_errors() {
+        let error = render("[[name|upper]]", &[("name", "card")]).unwrap_err();
+
+        assert!(error.to_string().contains("Unknown template filter 'upper' in [[name]]"));
+    }
+
+    #[test]
+    fn text_that_is_not_a_placeholder_is_left_alone() {
+        let template = "{{ index .Items 0 }} [[Name]] [ [name] ]";
+
+        assert_eq!(render(template, &[("name", "card")]).unwrap(), template);
+    }
+}
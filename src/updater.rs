@@ -0,0 +1,315 @@
+use anyhow::Result;
+use base64::Engine;
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use sha2::{Digest, Sha256};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::errors::{GoaError, GoaResult};
+use crate::utils;
+
+const RELEASE_PUBLIC_KEY: &str = match option_env!("GOA_RELEASE_PUBLIC_KEY") {
+    Some(key) => key,
+    None => include_str!("../release-key.pub"),
+};
+const RELEASE_DOWNLOAD_URL: &str = "https://github.com/kleeedolinux/goa-cli/releases/download";
+const RELEASE_URL_ENV: &str = "GOA_RELEASE_URL";
+const CHECKSUMS_FILE: &str = "SHA256SUMS";
+const SIGNATURE_FILE: &str = "SHA256SUMS.sig";
+const DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(120);
+
+pub fn install_release(tag: &str) -> Result<()> {
+    let public_key = release_public_key()?;
+    let asset = asset_name();
+    let base_url = release_base_url(tag);
+    let client = reqwest::blocking::Client::builder()
+        .timeout(DOWNLOAD_TIMEOUT)
+        .build()?;
+
+    utils::log_step(&format!("Downloading {} {}", asset, tag));
+    let checksums = download(&client, &format!("{}/{}", base_url, CHECKSUMS_FILE))?;
+    let signature = download(&client, &format!("{}/{}", base_url, SIGNATURE_FILE))?;
+
+    verify_signature(&public_key, &signed_payload(tag, &checksums), &signature)?;
+    utils::log_success(&format!("Verified the signature of {} for {}", CHECKSUMS_FILE, tag));
+
+    let expected = expected_checksum(&checksums, &asset)?;
+    let binary = download(&client, &format!("{}/{}", base_url, asset))?;
+    let actual = hex_digest(&binary);
+    if actual != expected {
+        return Err(GoaError::Update(format!(
+            "Checksum mismatch for {} (expected {}, got {})",
+            asset, expected, actual
+        )).into());
+    }
+    utils::log_success(&format!("Verified the SHA-256 checksum of {}", asset));
+
+    let executable = current_executable()?;
+    replace_executable(&executable, &binary)?;
+
+    utils::log_success(&format!("Installed {} to {}", tag, executable.display()));
+    utils::log_info("The previous version was kept; run `goa self rollback` to restore it");
+    Ok(())
+}
+
+pub fn rollback() -> Result<()> {
+    let executable = current_executable()?;
+    let backup = backup_path(&executable);
+
+    if !backup.exists() {
+        return Err(GoaError::Update(format!(
+            "No previous version found at {}; rollback is available after `goa self update`",
+            backup.display()
+        )).into());
+    }
+
+    let swap = executable.with_file_name(format!("{}.swap", file_name(&executable)));
+    fs::copy(&backup, &swap).map_err(GoaError::Io)?;
+    swap_into_place(&executable, &swap, &backup)?;
+
+    utils::log_success(&format!("Restored the previous version of {}", executable.display()));
+    utils::log_info("Run `goa self rollback` again to switch back");
+    Ok(())
+}
+
+fn release_public_key() -> GoaResult<VerifyingKey> {
+    if RELEASE_PUBLIC_KEY.trim().is_empty() {
+        return Err(GoaError::Update(
+            "This build has no release signing key, so updates cannot be verified; download the new version from the releases page".to_string(),
+        ));
+    }
+    parse_public_key(RELEASE_PUBLIC_KEY)
+}
+
+fn signed_payload(tag: &str, checksums: &[u8]) -> Vec<u8> {
+    let mut payload = format!("goa-release {}\n", tag).into_bytes();
+    payload.extend_from_slice(checksums);
+    payload
+}
+
+fn parse_public_key(encoded: &str) -> GoaResult<VerifyingKey> {
+    let bytes: [u8; 32] = base64::engine::general_purpose::STANDARD
+        .decode(encoded.trim())
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| GoaError::Update("The embedded release signing key is invalid".to_string()))?;
+
+    VerifyingKey::from_bytes(&bytes)
+        .map_err(|e| GoaError::Update(format!("The embedded release signing key is invalid: {}", e)))
+}
+
+fn verify_signature(public_key: &VerifyingKey, payload: &[u8], signature: &[u8]) -> GoaResult<()> {
+    let signature = String::from_utf8_lossy(signature);
+    let bytes: [u8; 64] = base64::engine::general_purpose::STANDARD
+        .decode(signature.trim())
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| GoaError::Update(format!("{} is not a valid signature", SIGNATURE_FILE)))?;
+
+    public_key
+        .verify(payload, &Signature::from_bytes(&bytes))
+        .map_err(|_| GoaError::Update(format!(
+            "The signature of {} does not match the release signing key and version; refusing to install",
+            CHECKSUMS_FILE
+        )))
+}
+
+fn expected_checksum(checksums: &[u8], asset: &str) -> GoaResult<String> {
+    String::from_utf8_lossy(checksums)
+        .lines()
+        .find_map(|line| {
+            let (hash, name) = line.trim().split_once(char::is_whitespace)?;
+            (name.trim().trim_start_matches('*') == asset).then(|| hash.to_lowercase())
+        })
+        .ok_or_else(|| GoaError::Update(format!("{} has no entry for {}", CHECKSUMS_FILE, asset)))
+}
+
+fn download(client: &reqwest::blocking::Client, url: &str) -> GoaResult<Vec<u8>> {
+    let response = client
+        .get(url)
+        .send()
+        .map_err(|e| GoaError::Update(format!("Failed to download {}: {}", url, e)))?;
+
+    if !response.status().is_success() {
+        return Err(GoaError::Update(format!("Failed to download {}: {}", url, response.status())));
+    }
+
+    response
+        .bytes()
+        .map(|bytes| bytes.to_vec())
+        .map_err(|e| GoaError::Update(format!("Failed to download {}: {}", url, e)))
+}
+
+fn replace_executable(executable: &Path, binary: &[u8]) -> GoaResult<()> {
+    let staged = executable.with_file_name(format!("{}.new", file_name(executable)));
+    fs::write(&staged, binary).map_err(GoaError::Io)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&staged, fs::Permissions::from_mode(0o755)).map_err(GoaError::Io)?;
+    }
+
+    swap_into_place(executable, &staged, &backup_path(executable))
+}
+
+fn swap_into_place(executable: &Path, replacement: &Path, backup: &Path) -> GoaResult<()> {
+    let previous = executable.with_file_name(format!("{}.old", file_name(executable)));
+
+    #[cfg(unix)]
+    {
+        fs::copy(executable, &previous).map_err(GoaError::Io)?;
+        if let Err(e) = fs::rename(replacement, executable) {
+            fs::remove_file(&previous).ok();
+            fs::remove_file(replacement).ok();
+            return Err(GoaError::Io(e));
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        fs::rename(executable, &previous).map_err(GoaError::Io)?;
+        if let Err(e) = fs::rename(replacement, executable) {
+            fs::rename(&previous, executable).ok();
+            fs::remove_file(replacement).ok();
+            return Err(GoaError::Io(e));
+        }
+    }
+
+    if backup.exists() {
+        fs::remove_file(backup).ok();
+    }
+    fs::rename(&previous, backup).map_err(GoaError::Io)
+}
+
+fn current_executable() -> GoaResult<PathBuf> {
+    let executable = env::current_exe().map_err(GoaError::Io)?;
+    Ok(fs::canonicalize(&executable).unwrap_or(executable))
+}
+
+fn backup_path(executable: &Path) -> PathBuf {
+    executable.with_file_name(format!("{}.previous", file_name(executable)))
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_else(|| "goa".to_string())
+}
+
+fn asset_name() -> String {
+    format!("goa-{}-{}{}", env::consts::OS, env::consts::ARCH, env::consts::EXE_SUFFIX)
+}
+
+fn release_base_url(tag: &str) -> String {
+    let base = env::var(RELEASE_URL_ENV)
+        .ok()
+        .filter(|url| !url.trim().is_empty())
+        .unwrap_or_else(|| RELEASE_DOWNLOAD_URL.to_string());
+    format!("{}/{}", base.trim_end_matches('/'), tag)
+}
+
+fn hex_digest(contents: &[u8]) -> String {
+    Sha256::digest(contents).iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    const CHECKSUMS: &str = "\
+0000000000000000000000000000000000000000000000000000000000000000  goa-linux-x86_64
+1111111111111111111111111111111111111111111111111111111111111111 *goa-windows-x86_64.exe
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA  goa-macos-aarch64
+";
+
+    fn signing_key(seed: u8) -> SigningKey {
+        SigningKey::from_bytes(&[seed; 32])
+    }
+
+    fn sign(key: &SigningKey, message: &[u8]) -> Vec<u8> {
+        base64::engine::general_purpose::STANDARD
+            .encode(key.sign(message).to_bytes())
+            .into_bytes()
+    }
+
+    #[test]
+    fn empty_release_key_is_rejected() {
+        assert!(parse_public_key("").is_err());
+        assert!(parse_public_key("not a key").unwrap_err().to_string().contains("release signing key is invalid"));
+    }
+
+    #[test]
+    fn signed_payload_starts_with_the_tag() {
+        assert_eq!(signed_payload("v0.2.0", b"abc  goa\n"), b"goa-release v0.2.0\nabc  goa\n".to_vec());
+    }
+
+    #[test]
+    fn rejects_signature_made_for_another_version() {
+        let key = signing_key(7);
+        let signature = sign(&key, &signed_payload("v0.1.0", CHECKSUMS.as_bytes()));
+
+        assert!(verify_signature(&key.verifying_key(), &signed_payload("v0.1.0", CHECKSUMS.as_bytes()), &signature).is_ok());
+        let error = verify_signature(&key.verifying_key(), &signed_payload("v0.2.0", CHECKSUMS.as_bytes()), &signature).unwrap_err();
+        assert!(error.to_string().contains("refusing to install"));
+    }
+
+    #[test]
+    fn accepts_good_signature() {
+        let key = signing_key(7);
+        let signature = sign(&key, CHECKSUMS.as_bytes());
+
+        assert!(verify_signature(&key.verifying_key(), CHECKSUMS.as_bytes(), &signature).is_ok());
+    }
+
+    #[test]
+    fn rejects_tampered_checksums() {
+        let key = signing_key(7);
+        let signature = sign(&key, CHECKSUMS.as_bytes());
+        let tampered = CHECKSUMS.replacen('0', "f", 1);
+
+        let error = verify_signature(&key.verifying_key(), tampered.as_bytes(), &signature).unwrap_err();
+        assert!(error.to_string().contains("does not match the release signing key"));
+    }
+
+    #[test]
+    fn rejects_signature_from_wrong_key() {
+        let signature = sign(&signing_key(7), CHECKSUMS.as_bytes());
+
+        let error = verify_signature(&signing_key(8).verifying_key(), CHECKSUMS.as_bytes(), &signature).unwrap_err();
+        assert!(error.to_string().contains("does not match the release signing key"));
+    }
+
+    #[test]
+    fn rejects_malformed_signature() {
+        let key = signing_key(7);
+
+        let error = verify_signature(&key.verifying_key(), CHECKSUMS.as_bytes(), b"not base64!").unwrap_err();
+        assert!(error.to_string().contains("is not a valid signature"));
+    }
+
+    #[test]
+    fn finds_checksum_for_asset() {
+        let checksum = expected_checksum(CHECKSUMS.as_bytes(), "goa-linux-x86_64").unwrap();
+        assert_eq!(checksum, "0".repeat(64));
+    }
+
+    #[test]
+    fn finds_checksum_for_binary_mode_entry() {
+        let checksum = expected_checksum(CHECKSUMS.as_bytes(), "goa-windows-x86_64.exe").unwrap();
+        assert_eq!(checksum, "1".repeat(64));
+    }
+
+    #[test]
+    fn lowercases_checksum() {
+        let checksum = expected_checksum(CHECKSUMS.as_bytes(), "goa-macos-aarch64").unwrap();
+        assert_eq!(checksum, "a".repeat(64));
+    }
+
+    #[test]
+    fn missing_checksum_entry_is_an_error() {
+        let error = expected_checksum(CHECKSUMS.as_bytes(), "goa-linux-aarch64").unwrap_err();
+        assert!(error.to_string().contains("has no entry for goa-linux-aarch64"));
+    }
+}
@@ -0,0 +1,231 @@
+use colored::Colorize;
+use serde_json::{json, Value};
+use std::env;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::Mutex;
+
+use crate::errors;
+
+const NO_COLOR_ENV: &str = "NO_COLOR";
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    Quiet = 0,
+    Normal = 1,
+    Verbose = 2,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    Human,
+    Json,
+}
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(Verbosity::Normal as u8);
+static JSON: AtomicBool = AtomicBool::new(false);
+static RESULT_DATA: Mutex<Option<Value>> = Mutex::new(None);
+
+pub fn init(verbosity: Verbosity, no_color: bool, format: OutputFormat) {
+    VERBOSITY.store(verbosity as u8, Ordering::Relaxed);
+    JSON.store(format == OutputFormat::Json, Ordering::Relaxed);
+
+    let no_color = no_color
+        || format == OutputFormat::Json
+        || env::var_os(NO_COLOR_ENV).map(|value| !value.is_empty()).unwrap_or(false);
+    if no_color {
+        colored::control::set_override(false);
+        console::set_colors_enabled(false);
+        console::set_colors_enabled_stderr(false);
+    }
+}
+
+pub fn verbosity() -> Verbosity {
+    match VERBOSITY.load(Ordering::Relaxed) {
+        0 => Verbosity::Quiet,
+        2 => Verbosity::Verbose,
+        _ => Verbosity::Normal,
+    }
+}
+
+pub fn is_quiet() -> bool {
+    verbosity() == Verbosity::Quiet
+}
+
+pub fn is_verbose() -> bool {
+    verbosity() == Verbosity::Verbose
+}
+
+pub fn is_json() -> bool {
+    JSON.load(Ordering::Relaxed)
+}
+
+pub fn shows_decorations() -> bool {
+    !is_quiet() && !is_json()
+}
+
+pub fn error(message: &str) {
+    if is_json() {
+        emit(json!({ "event": "error", "message": message }));
+    } else {
+        eprintln!("{} {}", "[ERROR]".red().bold(), message);
+    }
+}
+
+pub fn warning(message: &str) {
+    if is_json() {
+        emit(json!({ "event": "warning", "message": message }));
+    } else {
+        eprintln!("{} {}", "[WARNING]".yellow().bold(), message);
+    }
+}
+
+pub fn info(message: &str) {
+    if is_quiet() {
+        return;
+    }
+    if is_json() {
+        emit(json!({ "event": "info", "message": message }));
+    } else {
+        println!("{} {}", "[INFO]".blue().bold(), message);
+    }
+}
+
+pub fn success(message: &str) {
+    if is_quiet() {
+        return;
+    }
+    if is_json() {
+        emit(json!({ "event": "success", "message": message }));
+    } else {
+        println!("{} {}", "[SUCCESS]".green().bold(), message);
+    }
+}
+
+pub fn step(message: &str) {
+    if is_quiet() {
+        return;
+    }
+    if is_json() {
+        emit(json!({ "event": "step", "message": message }));
+    } else {
+        println!("{} {}", "[STEP]".cyan().bold(), message);
+    }
+}
+
+pub fn debug(message: &str) {
+    if !is_verbose() {
+        return;
+    }
+    if is_json() {
+        emit(json!({ "event": "debug", "message": message }));
+    } else {
+        eprintln!("{} {}", "[DEBUG]".magenta().bold(), message);
+    }
+}
+
+pub fn command(command: &Command) {
+    if !is_verbose() {
+        return;
+    }
+
+    let line = std::iter::once(command.get_program())
+        .chain(command.get_args())
+        .map(|part| quote(&part.to_string_lossy()))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    if is_json() {
+        let dir = command.get_current_dir().map(|dir| dir.display().to_string());
+        emit(json!({ "event": "command", "command": line, "dir": dir }));
+        return;
+    }
+
+    match command.get_current_dir() {
+        Some(dir) => eprintln!("{} {} {}", "[EXEC]".magenta().bold(), line, format!("(in {})", dir.display()).dimmed()),
+        None => eprintln!("{} {}", "[EXEC]".magenta().bold(), line),
+    }
+}
+
+pub fn file_created(path: &Path, kind: &str) {
+    if is_json() {
+        emit(json!({ "event": "file_created", "path": path.display().to_string(), "kind": kind }));
+    } else if kind == "directory" {
+        success(&format!("Created directory: {}", path.display()));
+    } else {
+        success(&format!("Created file: {}", path.display()));
+    }
+}
+
+pub fn file_deleted(path: &Path, kind: &str) {
+    if is_json() {
+        emit(json!({ "event": "file_deleted", "path": path.display().to_string(), "kind": kind }));
+    } else {
+        debug(&format!("Deleted {}", path.display()));
+    }
+}
+
+pub fn text(line: &str) {
+    if is_json() {
+        if line.trim().is_empty() {
+            return;
+        }
+        emit(json!({ "event": "output", "text": line }));
+    } else {
+        println!("{}", line);
+    }
+}
+
+pub fn set_result(data: Value) {
+    if let Ok(mut result) = RESULT_DATA.lock() {
+        *result = Some(data);
+    }
+}
+
+pub fn finish(command: &str, result: &anyhow::Result<()>) {
+    let data = RESULT_DATA.lock().ok().and_then(|mut result| result.take());
+
+    let error = match result {
+        Ok(()) => {
+            if is_json() {
+                emit(json!({ "event": "result", "command": command, "success": true, "exitCode": 0, "data": data }));
+            }
+            return;
+        }
+        Err(e) => e,
+    };
+
+    let message = match error.downcast_ref::<errors::GoaError>() {
+        Some(goa_error) => goa_error.to_string(),
+        None => format!("{:#}", error),
+    };
+    let exit_code = errors::exit_code(error);
+
+    if is_json() {
+        if errors::is_cancelled(error) {
+            emit(json!({ "event": "cancelled", "message": message }));
+        } else {
+            emit(json!({ "event": "error", "variant": errors::variant(error), "message": message }));
+        }
+        emit(json!({ "event": "result", "command": command, "success": false, "exitCode": exit_code, "data": data }));
+    } else if errors::is_cancelled(error) {
+        info(&message);
+    } else {
+        self::error(&message);
+    }
+}
+
+fn emit(event: Value) {
+    let mut stdout = io::stdout().lock();
+    writeln!(stdout, "{}", event).ok();
+    stdout.flush().ok();
+}
+
+fn quote(part: &str) -> String {
+    if !part.is_empty() && !part.contains(|c: char| c.is_whitespace() || matches!(c, '"' | '\'' | '$' | '`' | '\\')) {
+        return part.to_string();
+    }
+    format!("'{}'", part.replace('\'', r"'\''"))
+}
@@ -1,17 +1,19 @@
+pub mod logger;
+
 use crate::errors::{GoaError, GoaResult};
-use colored::Colorize;
 use dialoguer::{Confirm, Input, Select};
 use fs_extra::dir::CopyOptions;
 use regex::Regex;
 use std::fs;
 use std::path::Path;
+use std::process::Command;
 
 pub fn ensure_directory_exists(path: impl AsRef<Path>) -> GoaResult<()> {
     let path = path.as_ref();
     if !path.exists() {
         fs::create_dir_all(path)
             .map_err(|e| GoaError::Io(e))?;
-        log_success(&format!("Created directory: {}", path.display()));
+        logger::file_created(path, "directory");
     }
     Ok(())
 }
@@ -46,7 +48,7 @@ pub fn write_file(path: impl AsRef<Path>, contents: &str) -> GoaResult<()> {
     fs::write(path, contents)
         .map_err(|e| GoaError::Io(e))?;
 
-    log_success(&format!("Created file: {}", path.display()));
+    logger::file_created(path, "file");
     Ok(())
 }
 
@@ -96,6 +98,87 @@ pub fn validate_route_path(path: &str) -> Result<(), String> {
     Ok(())
 }
 
+pub fn validate_origin(origin: &str) -> Result<(), String> {
+    let origin_regex = Regex::new(r"^https?://(\*\.)?[A-Za-z0-9-]+(\.[A-Za-z0-9-]+)*(:\d{1,5})?$").unwrap();
+    if origin == "*" {
+        Ok(())
+    } else if origin.is_empty() {
+        Err("Origin cannot be empty".to_string())
+    } else if origin.ends_with('/') {
+        Err("Origin must not end with '/' (use e.g. https://example.com)".to_string())
+    } else if !origin_regex.is_match(origin) {
+        Err("Origin must be '*' or a scheme and host such as https://example.com or http://localhost:3000".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+pub fn validate_asset_url(url: &str) -> Result<(), String> {
+    let url_regex = Regex::new(r"^https?://[A-Za-z0-9-]+(\.[A-Za-z0-9-]+)*(:\d{1,5})?(/\S*)?$").unwrap();
+    if url.is_empty() {
+        Err("URL cannot be empty".to_string())
+    } else if url.starts_with('/') && !url.starts_with("//") && !url.contains(char::is_whitespace) {
+        Ok(())
+    } else if !url_regex.is_match(url) {
+        Err("URL must be an http(s) URL such as https://cdn.example.com/lib.js or a path starting with '/'".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+pub fn validate_port(port: &str) -> Result<(), String> {
+    match port.parse::<u32>() {
+        Ok(port) if (1..=65535).contains(&port) => Ok(()),
+        Ok(_) => Err("Port must be between 1 and 65535".to_string()),
+        Err(_) => Err("Port must be a whole number between 1 and 65535".to_string()),
+    }
+}
+
+pub fn validate_positive_integer(value: &str) -> Result<(), String> {
+    match value.parse::<u32>() {
+        Ok(0) => Err("Value must be greater than 0".to_string()),
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Value must be a whole number between 1 and {}", u32::MAX)),
+    }
+}
+
+pub fn validate_directory(project_dir: &Path, dir: &str) -> Result<(), String> {
+    if dir.is_empty() {
+        return Err("Directory cannot be empty".to_string());
+    }
+
+    let path = project_dir.join(dir);
+    if path.is_dir() {
+        return Ok(());
+    }
+    if path.exists() {
+        return Err(format!("{} exists but is not a directory", dir));
+    }
+
+    let existing_parent = path.ancestors().skip(1).find(|ancestor| ancestor.exists());
+    match existing_parent {
+        Some(parent) if !parent.is_dir() => Err(format!("{} cannot be created because {} is a file", dir, parent.display())),
+        Some(parent) if fs::metadata(parent).map(|m| m.permissions().readonly()).unwrap_or(true) => {
+            Err(format!("{} cannot be created because {} is not writable", dir, parent.display()))
+        }
+        Some(_) => Ok(()),
+        None => Err(format!("{} cannot be created", dir)),
+    }
+}
+
+pub fn validate_layout_path(project_dir: &Path, layout: &str) -> Result<(), String> {
+    let path = project_dir.join(layout);
+    if layout.is_empty() {
+        Err("Layout path cannot be empty".to_string())
+    } else if !path.is_file() {
+        Err(format!("Layout file {} does not exist", layout))
+    } else if path.extension().and_then(|e| e.to_str()) != Some("html") {
+        Err("Layout must be an .html file".to_string())
+    } else {
+        Ok(())
+    }
+}
+
 pub fn prompt_input<T: AsRef<str>>(prompt: T, default: Option<String>) -> GoaResult<String> {
     let input = Input::new();
     let input_with_prompt = input.with_prompt(prompt.as_ref());
@@ -107,7 +190,24 @@ pub fn prompt_input<T: AsRef<str>>(prompt: T, default: Option<String>) -> GoaRes
     };
     
     input_with_default.interact()
-        .map_err(|e| GoaError::Other(format!("Input prompt failed: {}", e)))
+        .map_err(|e| prompt_error("Input", e))
+}
+
+pub fn prompt_validated_input<T, F>(prompt: T, default: Option<String>, validator: F) -> GoaResult<String>
+where
+    T: AsRef<str>,
+    F: Fn(&str) -> Result<(), String>,
+{
+    let mut input = Input::<String>::new().with_prompt(prompt.as_ref());
+    if let Some(default_value) = default {
+        input = input.default(default_value);
+    }
+
+    input
+        .validate_with(|value: &String| validator(value.trim()))
+        .interact()
+        .map(|value| value.trim().to_string())
+        .map_err(|e| prompt_error("Input", e))
 }
 
 pub fn prompt_confirm<T: AsRef<str>>(prompt: T, default: bool) -> GoaResult<bool> {
@@ -115,40 +215,77 @@ pub fn prompt_confirm<T: AsRef<str>>(prompt: T, default: bool) -> GoaResult<bool
         .with_prompt(prompt.as_ref())
         .default(default)
         .interact()
-        .map_err(|e| GoaError::Other(format!("Confirmation prompt failed: {}", e)))
+        .map_err(|e| prompt_error("Confirmation", e))
 }
 
-#[allow(dead_code)]
 pub fn prompt_select<T: AsRef<str>>(prompt: T, options: &[String]) -> GoaResult<usize> {
     Select::new()
         .with_prompt(prompt.as_ref())
         .items(options)
         .default(0)
         .interact()
-        .map_err(|e| GoaError::Other(format!("Selection prompt failed: {}", e)))
+        .map_err(|e| prompt_error("Selection", e))
+}
+
+fn prompt_error(kind: &str, error: dialoguer::Error) -> GoaError {
+    match error {
+        dialoguer::Error::IO(e) if e.kind() == std::io::ErrorKind::Interrupted => {
+            console::Term::stderr().show_cursor().ok();
+            GoaError::Cancelled("Cancelled".to_string())
+        }
+        e => GoaError::Other(format!("{} prompt failed: {}", kind, e)),
+    }
 }
 
 pub fn log_error(message: &str) {
-    eprintln!("{} {}", "[ERROR]".red().bold(), message);
+    logger::error(message);
 }
 
 pub fn log_warning(message: &str) {
-    eprintln!("{} {}", "[WARNING]".yellow().bold(), message);
+    logger::warning(message);
 }
 
 pub fn log_info(message: &str) {
-    println!("{} {}", "[INFO]".blue().bold(), message);
+    logger::info(message);
 }
 
 pub fn log_success(message: &str) {
-    println!("{} {}", "[SUCCESS]".green().bold(), message);
+    logger::success(message);
 }
 
 pub fn log_step(message: &str) {
-    println!("{} {}", "[STEP]".cyan().bold(), message);
+    logger::step(message);
+}
+
+pub fn log_debug(message: &str) {
+    logger::debug(message);
+}
+
+pub fn log_command(command: &Command) {
+    logger::command(command);
 }
 
-pub fn update_main_imports(main_path: &Path, api_route: &str) -> GoaResult<()> {
+pub fn log_file_deleted(path: &Path, kind: &str) {
+    logger::file_deleted(path, kind);
+}
+
+pub fn go_module_name(project_dir: &Path) -> String {
+    fs::read_to_string(project_dir.join("go.mod"))
+        .ok()
+        .and_then(|content| {
+            content
+                .lines()
+                .find_map(|line| line.trim().strip_prefix("module ").map(|m| m.trim().trim_matches('"').to_string()))
+        })
+        .unwrap_or_else(|| "goonairplanes".to_string())
+}
+
+pub fn api_import_path(project_dir: &Path, app_dir: &str, api_route: &str) -> String {
+    let app_dir = app_dir.trim_start_matches("./").trim_matches('/');
+    format!("{}/{}/api/{}", go_module_name(project_dir), app_dir, api_route)
+}
+
+pub fn update_main_imports(main_path: &Path, import_path: &str) -> GoaResult<()> {
     if !main_path.exists() {
         return Err(GoaError::InvalidPath(format!(
             "Main file does not exist: {}",
@@ -160,11 +297,11 @@ pub fn update_main_imports(main_path: &Path, api_route: &str) -> GoaResult<()> {
         .map_err(|e| GoaError::Io(e))?;
 
     
-    let import_line = format!("\t_ \"goonairplanes/app/api/{}\"", api_route);
+    let import_line = format!("\t_ \"{}\"", import_path);
 
     
     if content.contains(&import_line) {
-        log_info(&format!("Import for {} already exists in main.go", api_route));
+        log_info(&format!("Import for {} already exists in main.go", import_path));
         return Ok(());
     }
 
@@ -178,14 +315,14 @@ pub fn update_main_imports(main_path: &Path, api_route: &str) -> GoaResult<()> {
         fs::write(main_path, new_content)
             .map_err(|e| GoaError::Io(e))?;
 
-        log_success(&format!("Added import for {} to main.go", api_route));
+        log_success(&format!("Added import for {} to main.go", import_path));
         return Ok(());
     }
 
     Err(GoaError::Other("Failed to parse main.go imports".to_string()))
 }
 
-pub fn remove_main_import(main_path: &Path, api_route: &str) -> GoaResult<()> {
+pub fn remove_main_import(main_path: &Path, import_path: &str) -> GoaResult<()> {
     if !main_path.exists() {
         return Err(GoaError::InvalidPath(format!(
             "Main file does not exist: {}",
@@ -197,11 +334,11 @@ pub fn remove_main_import(main_path: &Path, api_route: &str) -> GoaResult<()> {
         .map_err(|e| GoaError::Io(e))?;
 
     
-    let import_line = format!("\t_ \"goonairplanes/app/api/{}\"", api_route);
+    let import_line = format!("\t_ \"{}\"", import_path);
 
     
     if !content.contains(&import_line) {
-        log_info(&format!("Import for {} not found in main.go", api_route));
+        log_info(&format!("Import for {} not found in main.go", import_path));
         return Ok(());
     }
 
@@ -211,6 +348,37 @@ pub fn remove_main_import(main_path: &Path, api_route: &str) -> GoaResult<()> {
     fs::write(main_path, new_content)
         .map_err(|e| GoaError::Io(e))?;
 
-    log_success(&format!("Removed import for {} from main.go", api_route));
+    log_success(&format!("Removed import for {} from main.go", import_path));
     Ok(())
-} 
\ No newline at end of file
+}
+
+pub fn is_command_available(command: &str) -> bool {
+    #[cfg(windows)]
+    let check_command = Command::new("where").arg(command).output();
+    
+    #[cfg(not(windows))]
+    let check_command = Command::new("which").arg(command).output();
+    
+    match check_command {
+        Ok(output) => output.status.success(),
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interrupted_prompt_is_a_cancellation() {
+        let interrupted = dialoguer::Error::IO(std::io::Error::new(std::io::ErrorKind::Interrupted, "read interrupted"));
+        let error = prompt_error("Confirmation", interrupted);
+        assert!(matches!(error, GoaError::Cancelled(_)));
+        assert_eq!(error.exit_code(), 130);
+
+        let failed = dialoguer::Error::IO(std::io::Error::other("not a terminal"));
+        let error = prompt_error("Confirmation", failed);
+        assert_eq!(error.to_string(), "Confirmation prompt failed: IO error: not a terminal");
+        assert_eq!(error.exit_code(), 1);
+    }
+}
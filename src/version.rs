@@ -1,18 +1,106 @@
 use anyhow::Result;
 use colored::Colorize;
 use serde::{Deserialize, Serialize};
+use semver::Version;
+use std::cmp::Ordering;
+use std::env;
 use std::time::{Duration, SystemTime};
 use std::fs;
 use std::path::PathBuf;
-use std::process::Command;
-use dirs;
+use std::thread;
+
+use crate::errors::GoaError;
+use crate::settings::UserSettings;
+use crate::updater;
+use crate::utils;
+use crate::utils::logger;
 
 const VERSION_CHECK_URL: &str = "https://re.juliaklee.wtf/goa-cli/version";
 const VERSION_CHECK_INTERVAL: Duration = Duration::from_secs(1 * 60 * 60); 
+const VERSION_CHECK_TIMEOUT: Duration = Duration::from_secs(3);
+
+const NO_UPDATE_CHECK_ENV: &str = "GOA_NO_UPDATE_CHECK";
+const VERSION_URL_ENV: &str = "GOA_VERSION_URL";
 
 #[derive(Debug, Serialize, Deserialize)]
-struct VersionResponse {
+struct ReleaseIndex {
+    #[serde(default)]
+    version: Option<String>,
+    #[serde(default)]
+    releases: Vec<ReleaseInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ReleaseInfo {
     version: String,
+    #[serde(default)]
+    channel: Channel,
+    #[serde(default)]
+    date: Option<String>,
+    #[serde(default)]
+    changelog: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Channel {
+    #[default]
+    Stable,
+    Beta,
+}
+
+impl std::fmt::Display for Channel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Channel::Stable => write!(f, "stable"),
+            Channel::Beta => write!(f, "beta"),
+        }
+    }
+}
+
+impl Channel {
+    fn includes(&self, release: &ReleaseInfo) -> bool {
+        *self == Channel::Beta || release.channel == Channel::Stable
+    }
+}
+
+pub enum UpdateStatus {
+    UpToDate,
+    Available(String),
+    Ahead(String),
+}
+
+impl ReleaseIndex {
+    fn releases(&self) -> Vec<ReleaseInfo> {
+        if !self.releases.is_empty() {
+            return self.releases.clone();
+        }
+
+        self.version
+            .iter()
+            .map(|version| ReleaseInfo {
+                version: version.clone(),
+                channel: Channel::Stable,
+                date: None,
+                changelog: None,
+            })
+            .collect()
+    }
+
+    fn latest(&self, channel: Channel) -> Option<ReleaseInfo> {
+        self.releases()
+            .into_iter()
+            .filter(|release| channel.includes(release))
+            .filter_map(|release| parse_version(&release.version).map(|version| (version, release)))
+            .max_by(|(a, _), (b, _)| compare_versions(a, b))
+            .map(|(_, release)| release)
+    }
+
+    fn find(&self, version: &str) -> Option<ReleaseInfo> {
+        self.releases()
+            .into_iter()
+            .find(|release| normalize_version(&release.version) == normalize_version(version))
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -33,183 +121,422 @@ fn get_cache_path() -> PathBuf {
     path
 }
 
+fn version_url(settings: &UserSettings) -> String {
+    env::var(VERSION_URL_ENV)
+        .ok()
+        .filter(|url| !url.trim().is_empty())
+        .or_else(|| settings.version_url.clone())
+        .unwrap_or_else(|| VERSION_CHECK_URL.to_string())
+}
+
+fn update_check_enabled(settings: &UserSettings) -> bool {
+    let disabled_by_env = env::var(NO_UPDATE_CHECK_ENV)
+        .map(|value| !matches!(value.as_str(), "" | "0" | "false"))
+        .unwrap_or(false);
+
+    !disabled_by_env && settings.update_check != Some(false)
+}
+
+fn http_client() -> Result<reqwest::blocking::Client> {
+    Ok(reqwest::blocking::Client::builder()
+        .timeout(VERSION_CHECK_TIMEOUT)
+        .build()?)
+}
+
+fn read_cache(cache_path: &PathBuf) -> Option<VersionCache> {
+    let cache_data = fs::read_to_string(cache_path).ok()?;
+    serde_json::from_str(&cache_data).ok()
+}
+
+fn parse_version(version: &str) -> Option<Version> {
+    Version::parse(version.trim().trim_start_matches('v')).ok()
+}
+
+fn compare_versions(a: &Version, b: &Version) -> Ordering {
+    (a.major, a.minor, a.patch, &a.pre).cmp(&(b.major, b.minor, b.patch, &b.pre))
+}
+
+fn current_semver() -> Version {
+    parse_version(get_current_version()).expect("CARGO_PKG_VERSION is a valid semantic version")
+}
+
+fn update_status(latest: &str) -> UpdateStatus {
+    match parse_version(latest).map(|latest| compare_versions(&latest, &current_semver())) {
+        Some(Ordering::Greater) => UpdateStatus::Available(normalize_version(latest)),
+        Some(Ordering::Less) => UpdateStatus::Ahead(normalize_version(latest)),
+        _ => UpdateStatus::UpToDate,
+    }
+}
+
+fn normalize_version(version: &str) -> String {
+    format!("v{}", version.trim().trim_start_matches('v'))
+}
+
+fn fetch_index(url: &str) -> Result<ReleaseIndex> {
+    let local_path = url.strip_prefix("file://").unwrap_or(url);
+    if !url.contains("://") || url.starts_with("file://") {
+        let content = fs::read_to_string(local_path)
+            .map_err(|e| anyhow::anyhow!("Failed to read release index {}: {}", local_path, e))?;
+        return Ok(serde_json::from_str(&content)?);
+    }
+
+    let response = http_client()?.get(url).send()?;
+    if !response.status().is_success() {
+        return Err(anyhow::anyhow!("Version endpoint returned {}", response.status()));
+    }
+
+    Ok(response.json()?)
+}
+
+fn fetch_latest_version(url: &str) -> Result<String> {
+    let index = fetch_index(url)?;
+    let latest = index.latest(Channel::Stable)
+        .ok_or_else(|| anyhow::anyhow!("The release index does not list any stable release"))?;
+    let version_info = ReleaseInfo { version: normalize_version(&latest.version), ..latest };
+
+    let now = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH)?.as_secs();
+    let cache = VersionCache {
+        last_checked: now,
+        latest_version: version_info.version.clone(),
+    };
+
+    let cache_path = get_cache_path();
+    let temp_path = cache_path.with_extension("json.tmp");
+    fs::write(&temp_path, serde_json::to_string(&cache)?)?;
+    fs::rename(&temp_path, &cache_path)?;
+
+    Ok(version_info.version)
+}
+
 pub fn check_version() -> Result<()> {
+    let settings = UserSettings::load();
+    if !update_check_enabled(&settings) {
+        return Ok(());
+    }
+
     let cache_path = get_cache_path();
-    
-    let latest_version = if should_check_for_updates(&cache_path)? {
-        let client = reqwest::blocking::Client::new();
-        let response = client.get(VERSION_CHECK_URL).send()?;
-        
-        if response.status().is_success() {
-            let version_info: VersionResponse = response.json()?;
-            
-            let now = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH)?.as_secs();
-            let cache = VersionCache {
-                last_checked: now,
-                latest_version: version_info.version.clone(),
-            };
-            
-            let cache_json = serde_json::to_string(&cache)?;
-            fs::write(&cache_path, cache_json)?;
-            
-            version_info.version
-        } else {
-            format!("v{}", get_current_version())
-        }
-    } else {
-        let cache_data = fs::read_to_string(&cache_path)?;
-        let cache: VersionCache = serde_json::from_str(&cache_data)?;
-        cache.latest_version
-    };
-    
-    if latest_version != format!("v{}", get_current_version()) {
-        println!();
-        println!("{} {} → {}", 
-            "A new version of GOA CLI is available:".yellow(),
-            format!("v{}", get_current_version()).bright_red(),
-            latest_version.bright_green()
-        );
-        println!("Run {} to upgrade.", "`goa self update`".cyan());
-        println!();
+    let cache = read_cache(&cache_path);
+
+    if should_check_for_updates(cache.as_ref())? {
+        let url = version_url(&settings);
+        thread::spawn(move || {
+            let _ = fetch_latest_version(&url);
+        });
     }
+
+    if logger::is_quiet() {
+        return Ok(());
+    }
+
+    let latest_version = match cache.map(|cache| update_status(&cache.latest_version)) {
+        Some(UpdateStatus::Available(latest_version)) => latest_version,
+        _ => return Ok(()),
+    };
+
+    println!();
+    println!("{} {} → {}", 
+        "A new version of GOA CLI is available:".yellow(),
+        format!("v{}", get_current_version()).bright_red(),
+        latest_version.bright_green()
+    );
+    println!("Run {} to upgrade.", "`goa self update`".cyan());
+    println!();
     
     Ok(())
 }
 
-fn should_check_for_updates(cache_path: &PathBuf) -> Result<bool> {
-    if !cache_path.exists() {
-        return Ok(true);
-    }
-    
-    
-    let cache_data = fs::read_to_string(cache_path)?;
-    let cache: VersionCache = serde_json::from_str(&cache_data)?;
-    
+fn should_check_for_updates(cache: Option<&VersionCache>) -> Result<bool> {
+    let cache = match cache {
+        Some(cache) => cache,
+        None => return Ok(true),
+    };
     
     let now = SystemTime::now()
         .duration_since(SystemTime::UNIX_EPOCH)?
         .as_secs();
     
     
-    Ok(now - cache.last_checked > VERSION_CHECK_INTERVAL.as_secs())
+    Ok(now.saturating_sub(cache.last_checked) > VERSION_CHECK_INTERVAL.as_secs())
 }
 
-pub fn handle_self_update() -> Result<()> {
-    println!("Checking for updates...");
+pub fn set_update_check(enabled: bool) -> Result<()> {
+    let mut settings = UserSettings::load();
+    settings.update_check = Some(enabled);
+    settings.save()?;
+
+    if enabled {
+        logger::text("Update checks enabled.");
+    } else {
+        logger::text(&format!("Update checks disabled. Run {} to turn them back on.", "`goa self update-check on`".cyan()));
+    }
+    Ok(())
+}
+
+pub fn handle_self_update(channel: Channel, version: Option<String>) -> Result<()> {
+    logger::text("Checking for updates...");
     
     let current_version = format!("v{}", get_current_version());
     
-    let client = reqwest::blocking::Client::new();
-    let response = client.get(VERSION_CHECK_URL).send()?;
+    let index = fetch_index(&version_url(&UserSettings::load()))
+        .map_err(|e| anyhow::anyhow!("Failed to check for updates: {}", e))?;
     
-    if !response.status().is_success() {
-        return Err(anyhow::anyhow!("Failed to check for updates"));
-    }
+    let target = select_release(&index, channel, version.as_deref())?;
+    let target_version = normalize_version(&target.version);
     
-    let version_info: VersionResponse = response.json()?;
-    let latest_version = version_info.version;
+    let ordering = parse_version(&target.version)
+        .map(|target| compare_versions(&target, &current_semver()))
+        .ok_or_else(|| anyhow::anyhow!("{} is not a valid semantic version", target.version))?;
     
-    if latest_version == current_version {
-        println!("You already have the latest version ({}).", current_version);
-        return Ok(());
-    }
-    
-    println!("{} {} → {}", 
-        "Updating GOA CLI:".yellow(),
-        current_version.bright_red(),
-        latest_version.bright_green()
-    );
-    
-    #[cfg(target_os = "windows")]
-    {
-        println!("Downloading Windows installer...");
-        
-        let temp_dir = std::env::temp_dir();
-        let installer_path = temp_dir.join("goa_install.ps1");
-        
-        let installer_url = "https://raw.githubusercontent.com/kleeedolinux/goa-cli/master/scripts/install.ps1";
-        let installer_content = reqwest::blocking::get(installer_url)?.text()?;
-        fs::write(&installer_path, installer_content)?;
-        
-        println!("Running installer...");
-        
-        let ps_status = Command::new("powershell")
-            .arg("-ExecutionPolicy")
-            .arg("Bypass")
-            .arg("-File")
-            .arg(&installer_path)
-            .status()?;
-        
-        if !ps_status.success() {
-            return Err(anyhow::anyhow!("Failed to run the installer"));
+    match ordering {
+        Ordering::Equal => {
+            logger::text(&format!("You already have version {}.", current_version));
+            return Ok(());
         }
-        
-        fs::remove_file(installer_path).ok();
-    }
-    
-    #[cfg(target_os = "macos")]
-    {
-        println!("Downloading macOS installer...");
-        
-        let status = Command::new("bash")
-            .arg("-c")
-            .arg("curl -sSL https://raw.githubusercontent.com/kleeedolinux/goa-cli/master/scripts/macuser.sh | bash")
-            .status()?;
-        
-        if !status.success() {
-            return Err(anyhow::anyhow!("Failed to run the installer"));
+        Ordering::Less if version.is_none() => {
+            logger::text(&format!(
+                "You are running a development build ({}) that is ahead of the latest {} release ({}).",
+                current_version, channel, target_version
+            ));
+            logger::text(&format!("Pass {} to install that release anyway.", format!("--version {}", target_version).cyan()));
+            return Ok(());
         }
+        Ordering::Less => logger::text(&format!("{} {} → {}", 
+            "Downgrading GOA CLI:".yellow(),
+            current_version.bright_red(),
+            target_version.bright_green()
+        )),
+        Ordering::Greater => logger::text(&format!("{} {} → {}", 
+            "Updating GOA CLI:".yellow(),
+            current_version.bright_red(),
+            target_version.bright_green()
+        )),
     }
     
-    #[cfg(target_os = "linux")]
-    {
-        println!("Downloading Linux installer...");
-        
-        let status = Command::new("bash")
-            .arg("-c")
-            .arg("curl -sSL https://raw.githubusercontent.com/kleeedolinux/goa-cli/master/scripts/install.sh | bash")
-            .status()?;
-        
-        if !status.success() {
-            return Err(anyhow::anyhow!("Failed to run the installer"));
-        }
-    }
+    print_changelog(&changelog_entries(&index, &target, version.is_none().then_some(channel), &current_semver()));
     
-    #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
-    {
-        return Err(anyhow::anyhow!("Self-update is not supported on this platform"));
+    if console::user_attended() && !utils::prompt_confirm(format!("Install {}?", target_version), true)? {
+        return Err(GoaError::Cancelled("Update cancelled".to_string()).into());
     }
     
-    println!("Self-update completed successfully!");
+    updater::install_release(&target_version)?;
+    
+    logger::text("Self-update completed successfully!");
     Ok(())
 }
 
-pub fn get_latest_version() -> Result<String> {
-    let cache_path = get_cache_path();
+fn select_release(index: &ReleaseIndex, channel: Channel, version: Option<&str>) -> Result<ReleaseInfo> {
+    match version {
+        Some(version) => index.find(version)
+            .ok_or_else(|| anyhow::anyhow!("Version {} is not listed in the release index", normalize_version(version))),
+        None => index.latest(channel)
+            .ok_or_else(|| anyhow::anyhow!("No release found on the {} channel", channel)),
+    }
+}
+
+fn changelog_entries(index: &ReleaseIndex, target: &ReleaseInfo, channel: Option<Channel>, current: &Version) -> Vec<ReleaseInfo> {
+    let (channel, target_version) = match (channel, parse_version(&target.version)) {
+        (Some(channel), Some(target_version)) => (channel, target_version),
+        _ => return vec![target.clone()],
+    };
+    
+    let mut releases: Vec<(Version, ReleaseInfo)> = index.releases()
+        .into_iter()
+        .filter(|release| channel.includes(release))
+        .filter_map(|release| parse_version(&release.version).map(|version| (version, release)))
+        .filter(|(version, _)| {
+            compare_versions(version, current) == Ordering::Greater
+                && compare_versions(version, &target_version) != Ordering::Greater
+        })
+        .collect();
     
-    if !cache_path.exists() || should_check_for_updates(&cache_path)? {
-        let client = reqwest::blocking::Client::new();
-        let response = client.get(VERSION_CHECK_URL).send()?;
-        
-        if response.status().is_success() {
-            let version_info: VersionResponse = response.json()?;
-            
-            let now = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH)?.as_secs();
-            let cache = VersionCache {
-                last_checked: now,
-                latest_version: version_info.version.clone(),
-            };
-            
-            let cache_json = serde_json::to_string(&cache)?;
-            fs::write(&cache_path, cache_json)?;
-            
-            Ok(version_info.version)
-        } else {
-            Ok(format!("v{}", get_current_version()))
+    releases.sort_by(|(a, _), (b, _)| compare_versions(b, a));
+    releases.into_iter().map(|(_, release)| release).collect()
+}
+
+fn print_changelog(releases: &[ReleaseInfo]) {
+    for line in changelog_lines(releases) {
+        logger::text(&line);
+    }
+}
+
+fn changelog_lines(releases: &[ReleaseInfo]) -> Vec<String> {
+    let releases: Vec<&ReleaseInfo> = releases.iter().filter(|release| release.changelog.is_some()).collect();
+    if releases.is_empty() {
+        return Vec::new();
+    }
+    
+    let mut lines = vec![String::new(), "What's new:".bold().to_string()];
+    for release in releases {
+        let date = release.date.as_deref().map(|date| format!(" ({})", date)).unwrap_or_default();
+        lines.push(format!("  {}{}", normalize_version(&release.version).bright_green(), date.dimmed()));
+        for line in release.changelog.as_deref().unwrap_or_default().lines() {
+            lines.push(format!("    {}", line));
         }
-    } else {
-        let cache_data = fs::read_to_string(&cache_path)?;
-        let cache: VersionCache = serde_json::from_str(&cache_data)?;
-        Ok(cache.latest_version)
     }
-} 
\ No newline at end of file
+    lines.push(String::new());
+    lines
+}
+
+pub fn get_update_status() -> Result<UpdateStatus> {
+    Ok(update_status(&get_latest_version()?))
+}
+
+fn get_latest_version() -> Result<String> {
+    let settings = UserSettings::load();
+    if !update_check_enabled(&settings) {
+        return Err(anyhow::anyhow!("update checks are disabled"));
+    }
+    let cache = read_cache(&get_cache_path());
+    
+    match cache {
+        Some(cache) if !should_check_for_updates(Some(&cache))? => Ok(cache.latest_version),
+        _ => fetch_latest_version(&version_url(&settings)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INDEX_FIXTURE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/release-index.json");
+
+    fn fixture_index() -> ReleaseIndex {
+        fetch_index(INDEX_FIXTURE).expect("release index fixture")
+    }
+
+    fn versions(releases: &[ReleaseInfo]) -> Vec<String> {
+        releases.iter().map(|release| normalize_version(&release.version)).collect()
+    }
+
+    fn plain(line: &str) -> String {
+        let ansi = regex::Regex::new(r"\x1b\[[0-9;]*m").unwrap();
+        ansi.replace_all(line, "").into_owned()
+    }
+
+    #[test]
+    fn reads_local_index_from_path_and_file_url() {
+        let from_path = fixture_index();
+        let from_url = fetch_index(&format!("file://{}", INDEX_FIXTURE)).unwrap();
+
+        assert_eq!(from_path.releases().len(), 5);
+        assert_eq!(versions(&from_url.releases()), versions(&from_path.releases()));
+    }
+
+    #[test]
+    fn missing_local_index_is_an_error() {
+        let error = fetch_index("/nonexistent/release-index.json").unwrap_err();
+        assert!(error.to_string().contains("Failed to read release index"));
+    }
+
+    #[test]
+    fn stable_channel_skips_beta_releases() {
+        let release = select_release(&fixture_index(), Channel::Stable, None).unwrap();
+        assert_eq!(release.version, "v0.2.1");
+    }
+
+    #[test]
+    fn beta_channel_includes_beta_releases() {
+        let release = select_release(&fixture_index(), Channel::Beta, None).unwrap();
+        assert_eq!(release.version, "v0.3.0-beta.1");
+    }
+
+    #[test]
+    fn pinned_version_is_found_with_or_without_prefix() {
+        let index = fixture_index();
+
+        assert_eq!(select_release(&index, Channel::Stable, Some("0.1.1")).unwrap().version, "v0.1.1");
+        assert_eq!(select_release(&index, Channel::Stable, Some("v0.3.0-beta.1")).unwrap().channel, Channel::Beta);
+    }
+
+    #[test]
+    fn pinned_missing_version_is_an_error() {
+        let error = select_release(&fixture_index(), Channel::Stable, Some("9.9.9")).unwrap_err();
+        assert_eq!(error.to_string(), "Version v9.9.9 is not listed in the release index");
+    }
+
+    #[test]
+    fn legacy_index_with_single_version_is_stable() {
+        let index: ReleaseIndex = serde_json::from_str(r#"{"version": "0.4.0"}"#).unwrap();
+
+        assert_eq!(select_release(&index, Channel::Stable, None).unwrap().version, "0.4.0");
+        assert!(select_release(&index, Channel::Stable, Some("0.5.0")).is_err());
+    }
+
+    #[test]
+    fn changelog_lists_releases_between_current_and_target() {
+        let index = fixture_index();
+        let current = parse_version("0.1.2").unwrap();
+        let target = select_release(&index, Channel::Stable, None).unwrap();
+
+        let entries = changelog_entries(&index, &target, Some(Channel::Stable), &current);
+        assert_eq!(versions(&entries), vec!["v0.2.1", "v0.2.0"]);
+
+        let target = select_release(&index, Channel::Beta, None).unwrap();
+        let entries = changelog_entries(&index, &target, Some(Channel::Beta), &current);
+        assert_eq!(versions(&entries), vec!["v0.3.0-beta.1", "v0.2.1", "v0.2.0"]);
+    }
+
+    #[test]
+    fn pinned_changelog_only_shows_target() {
+        let index = fixture_index();
+        let current = parse_version("0.1.2").unwrap();
+        let target = select_release(&index, Channel::Stable, Some("0.2.0")).unwrap();
+
+        assert_eq!(versions(&changelog_entries(&index, &target, None, &current)), vec!["v0.2.0"]);
+    }
+
+    #[test]
+    fn changelog_excerpt_output() {
+        let index = fixture_index();
+        let current = parse_version("0.1.2").unwrap();
+        let target = select_release(&index, Channel::Stable, None).unwrap();
+
+        let lines: Vec<String> = changelog_lines(&changelog_entries(&index, &target, Some(Channel::Stable), &current))
+            .iter()
+            .map(|line| plain(line))
+            .collect();
+
+        assert_eq!(lines, vec![
+            "",
+            "What's new:",
+            "  v0.2.1 (2026-09-20)",
+            "    Fix the export of nested dynamic routes",
+            "  v0.2.0 (2026-09-01)",
+            "    Add goa project export",
+            "    Add goa doctor",
+            "",
+        ]);
+    }
+
+    #[test]
+    fn compare_versions_orders_prereleases_before_releases() {
+        let version = |v: &str| parse_version(v).unwrap();
+
+        assert_eq!(compare_versions(&version("v0.2.0"), &version("0.1.9")), Ordering::Greater);
+        assert_eq!(compare_versions(&version("0.2.0-beta.1"), &version("0.2.0")), Ordering::Less);
+        assert_eq!(compare_versions(&version("0.2.0-beta.2"), &version("0.2.0-beta.10")), Ordering::Less);
+        assert_eq!(compare_versions(&version("1.0.0+build.5"), &version("v1.0.0")), Ordering::Equal);
+        assert!(parse_version("1.0").is_none());
+    }
+
+    #[test]
+    fn update_status_compares_against_current_version() {
+        let current = current_semver();
+        let newer = format!("{}.{}.{}", current.major, current.minor, current.patch + 1);
+        let older = format!("{}.{}.{}-alpha", current.major, current.minor, current.patch);
+
+        assert!(matches!(update_status(&newer), UpdateStatus::Available(v) if v == format!("v{}", newer)));
+        assert!(matches!(update_status(&older), UpdateStatus::Ahead(v) if v == format!("v{}", older)));
+        assert!(matches!(update_status(get_current_version()), UpdateStatus::UpToDate));
+        assert!(matches!(update_status("not a version"), UpdateStatus::UpToDate));
+    }
+
+    #[test]
+    fn changelog_is_empty_without_notes() {
+        let index = fixture_index();
+        let target = select_release(&index, Channel::Stable, Some("0.1.1")).unwrap();
+
+        assert!(changelog_lines(&[target]).is_empty());
+        assert!(changelog_lines(&index.releases()[..0]).is_empty());
+    }
+}